@@ -0,0 +1,173 @@
+use linear::{
+    error::LinearError,
+    matrix::matrix::Matrix,
+    vector::{shape::Shape, vector::Vector},
+};
+
+// Cholesky decomposition A = L * L^T of a symmetric positive definite matrix.
+pub struct CholeskyDecomposition {
+    l: Matrix<f64>,
+}
+
+impl CholeskyDecomposition {
+    pub fn new(a: &Matrix<f64>) -> Result<Self, LinearError> {
+        let n: usize = a.size().rows();
+        if n != a.size().cols() {
+            return Err(LinearError::NotSquare {
+                size: a.size().clone(),
+            });
+        }
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let upper: f64 = *a.get(i, j).unwrap();
+                let lower: f64 = *a.get(j, i).unwrap();
+                if (upper - lower).abs() > 1e-12 * upper.abs().max(lower.abs()).max(1.0) {
+                    return Err(LinearError::NotSymmetric);
+                }
+            }
+        }
+
+        let mut l: Vec<Vec<f64>> = vec![vec![0.0; n]; n];
+        for j in 0..n {
+            let mut diagonal: f64 = *a.get(j, j).unwrap();
+            for e in &l[j][..j] {
+                diagonal -= e * e;
+            }
+            if diagonal <= 0.0 || diagonal.is_nan() {
+                return Err(LinearError::NotPositiveDefinite { pivot: j });
+            }
+            l[j][j] = diagonal.sqrt();
+
+            for i in (j + 1)..n {
+                let mut val: f64 = *a.get(i, j).unwrap();
+                for (lik, ljk) in l[i][..j].iter().zip(&l[j][..j]) {
+                    val -= lik * ljk;
+                }
+                l[i][j] = val / l[j][j];
+            }
+        }
+
+        Ok(CholeskyDecomposition { l: Matrix::new(&l) })
+    }
+
+    pub fn l(&self) -> &Matrix<f64> {
+        &self.l
+    }
+
+    pub fn solve(&self, b: &Vector<f64>) -> Result<Vector<f64>, LinearError> {
+        let n: usize = self.l.size().rows();
+        if b.size() != n {
+            return Err(LinearError::DimensionMismatch {
+                left: self.l.size().clone(),
+                right: b.dimensions(),
+            });
+        }
+
+        // forward substitution: L * y = b
+        let mut y: Vec<f64> = vec![0.0; n];
+        for i in 0..n {
+            let mut val: f64 = *b.get(i).unwrap();
+            for (j, yj) in y.iter().enumerate().take(i) {
+                val -= self.l.get(i, j).unwrap() * yj;
+            }
+            y[i] = val / self.l.get(i, i).unwrap();
+        }
+
+        // back substitution: L^T * x = y
+        let mut x: Vec<f64> = vec![0.0; n];
+        for i in (0..n).rev() {
+            let mut val: f64 = y[i];
+            for (j, xj) in x.iter().enumerate().skip(i + 1) {
+                val -= self.l.get(j, i).unwrap() * xj;
+            }
+            x[i] = val / self.l.get(i, i).unwrap();
+        }
+
+        Ok(Vector::new(x, Shape::Col))
+    }
+
+    pub fn determinant(&self) -> f64 {
+        (0..self.l.size().rows())
+            .map(|i| self.l.get(i, i).unwrap().powi(2))
+            .product()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use linear::{
+        error::LinearError,
+        generator::Generator,
+        matrix::matrix::Matrix,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::CholeskyDecomposition;
+
+    #[test]
+    fn test_factor() {
+        let a: Matrix<f64> = get_default_matrix();
+        let cholesky: CholeskyDecomposition = CholeskyDecomposition::new(&a).unwrap();
+        let expected: Matrix<f64> = Matrix::new(&vec![
+            vec![2.0, 0.0, 0.0],
+            vec![6.0, 1.0, 0.0],
+            vec![-8.0, 5.0, 3.0],
+        ]);
+
+        assert!(cholesky.l().equals(&expected));
+        assert_eq!(cholesky.determinant(), 36.0);
+    }
+
+    #[test]
+    fn test_solve() {
+        let a: Matrix<f64> = get_default_matrix();
+        let cholesky: CholeskyDecomposition = CholeskyDecomposition::new(&a).unwrap();
+        let b: Vector<f64> = Vector::new(vec![1.0, 2.0, 3.0], Shape::Col);
+        let x: Vector<f64> = cholesky.solve(&b).unwrap();
+        let ax: Vector<f64> = a.vector_product(&x).unwrap();
+
+        for i in 0..b.size() {
+            assert!((b.get(i).unwrap() - ax.get(i).unwrap()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_solve_random() {
+        let a: Matrix<f64> = Matrix::random_spd(&mut Generator::seeded(17), 30, 1e3).unwrap();
+        let b: Vector<f64> =
+            Vector::random_normal(&mut Generator::seeded(18), 0.0, 1.0, 30, Shape::Col).unwrap();
+        let x: Vector<f64> = CholeskyDecomposition::new(&a).unwrap().solve(&b).unwrap();
+        let ax: Vector<f64> = a.vector_product(&x).unwrap();
+
+        for i in 0..b.size() {
+            assert!((b.get(i).unwrap() - ax.get(i).unwrap()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_not_symmetric() {
+        let a: Matrix<f64> = Matrix::new(&vec![vec![4.0, 1.0], vec![2.0, 3.0]]);
+        assert_eq!(
+            CholeskyDecomposition::new(&a).err(),
+            Some(LinearError::NotSymmetric)
+        );
+    }
+
+    #[test]
+    fn test_not_positive_definite() {
+        let a: Matrix<f64> = Matrix::new(&vec![vec![1.0, 2.0], vec![2.0, 1.0]]);
+        assert_eq!(
+            CholeskyDecomposition::new(&a).err(),
+            Some(LinearError::NotPositiveDefinite { pivot: 1 })
+        );
+    }
+
+    fn get_default_matrix() -> Matrix<f64> {
+        Matrix::new(&vec![
+            vec![4.0, 12.0, -16.0],
+            vec![12.0, 37.0, -43.0],
+            vec![-16.0, -43.0, 98.0],
+        ])
+    }
+}
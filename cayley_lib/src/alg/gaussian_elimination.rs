@@ -1,6 +1,7 @@
 use std::ops::{Add, Div, DivAssign, Mul, SubAssign};
 
 use linear::{
+    error::LinearError,
     matrix::matrix::Matrix,
     num::Num,
     vector::{shape::Shape, vector::Vector},
@@ -10,16 +11,29 @@ pub struct GaussianElimination {}
 
 impl GaussianElimination {
     // Gaussian elimination with partial pivoting
-    pub fn solve<T>(a: Matrix<T>, b: Vector<T>) -> Option<Vector<T>>
+    // Fails for a singular matrix, Matrix::rank or Matrix::determinant tell that in advance.
+    pub fn solve<T>(a: Matrix<T>, b: Vector<T>) -> Result<Vector<T>, LinearError>
     where
         T: Mul<Output = T> + Add<Output = T> + Div<Output = T> + SubAssign + DivAssign + Num,
     {
+        if a.size().rows() != a.size().cols() {
+            return Err(LinearError::NotSquare {
+                size: a.size().clone(),
+            });
+        }
+        if a.size().rows() != b.size() {
+            return Err(LinearError::DimensionMismatch {
+                left: a.size().clone(),
+                right: b.dimensions(),
+            });
+        }
+
         // make augmented matrix
         let mut augmented_matrix: Vec<Vec<T>> = vec![vec![T::zero(); b.size() + 1]; b.size()];
-        for r in 0..a.size().rows() {
-            let mut row: Vec<T> = a.get_row(r).iter().map(|e| *e.clone()).collect();
-            row.push(*b.get(r).unwrap());
-            augmented_matrix[r] = row;
+        for (r, augmented_row) in augmented_matrix.iter_mut().enumerate() {
+            let mut row: Vec<T> = a.get_row(r).into_iter().cloned().collect();
+            row.push(b.get(r).unwrap().clone());
+            *augmented_row = row;
         }
 
         // WP algorithm from Gaussian elimination page
@@ -29,8 +43,8 @@ impl GaussianElimination {
             let mut i_max: usize = k;
             let mut max: T = augmented_matrix[k][k].absolute();
 
-            for i in (k + 1)..b.size() {
-                let abs: T = augmented_matrix[i][k].absolute();
+            for (i, row) in augmented_matrix.iter().enumerate().skip(k + 1) {
+                let abs: T = row[k].absolute();
                 if abs > max {
                     i_max = i;
                     max = abs;
@@ -38,7 +52,7 @@ impl GaussianElimination {
             }
 
             if augmented_matrix[i_max][k] == T::zero() {
-                return None;
+                return Err(LinearError::SingularMatrix { pivot: k });
             }
 
             // swap rows(k, i_max)
@@ -47,17 +61,18 @@ impl GaussianElimination {
             augmented_matrix[i_max] = tmp;
 
             // Do for all rows below pivot:
-            for i in (k + 1)..b.size() {
+            let (upper, lower) = augmented_matrix.split_at_mut(k + 1);
+            let pivot_row: &[T] = &upper[k];
+            for row in lower.iter_mut() {
+                let factor: T = row[k].clone() / pivot_row[k].clone();
                 // Do for all remaining elements in current row:
-                for j in (k + 1)..(b.size() + 1) {
-                    let mut val: T = augmented_matrix[i][j];
-                    val -=
-                        augmented_matrix[k][j] * (augmented_matrix[i][k] / augmented_matrix[k][k]);
-                    augmented_matrix[i][j] = val;
+                for (e, p) in row.iter_mut().zip(pivot_row).skip(k + 1) {
+                    let product: T = p.clone() * factor.clone();
+                    *e -= product;
                 }
 
                 // Fill lower triangular matrix with zeros:
-                augmented_matrix[i][k] = T::zero();
+                row[k] = T::zero();
             }
         }
 
@@ -65,29 +80,145 @@ impl GaussianElimination {
         // now back substitute to get result.
         let mut x: Vec<T> = vec![T::zero(); b.size()];
         for i in (0..b.size()).rev() {
-            x[i] = augmented_matrix[i][b.size()];
+            x[i] = augmented_matrix[i][b.size()].clone();
             for j in (i + 1)..b.size() {
-                let mut val: T = x[i];
-                val -= augmented_matrix[i][j] * x[j];
-                x[i] = val;
+                let product: T = augmented_matrix[i][j].clone() * x[j].clone();
+                x[i] -= product;
             }
-            x[i] /= augmented_matrix[i][i]
+            x[i] /= augmented_matrix[i][i].clone()
         }
 
-        return Some(Vector::new(x, Shape::Col));
+        Ok(Vector::new(x, Shape::Col))
     }
 }
 
 #[cfg(test)]
 mod test {
     use linear::{
-        matrix::matrix::Matrix,
+        complex::Complex,
+        error::LinearError,
+        matrix::{matrix::Matrix, size::Size},
+        rational::Rational,
         vector::{shape::Shape, vector::Vector},
     };
 
     use super::GaussianElimination;
 
     #[test]
+    fn test_solve_rational() {
+        // Hilbert matrix, badly conditioned but solved exactly
+        let a: Matrix<Rational> = Matrix::hilbert(4);
+        let x: Vector<Rational> = Vector::new(
+            vec![
+                Rational::from(1),
+                Rational::new(-1, 2),
+                Rational::new(2, 3),
+                Rational::from(-4),
+            ],
+            Shape::Col,
+        );
+        let b: Vector<Rational> = a.vector_product(&x).unwrap();
+
+        assert_eq!(
+            GaussianElimination::solve(a, b).unwrap().elements(),
+            x.elements()
+        );
+    }
+
+    #[test]
+    fn test_solve_rational_large() {
+        // the fractions in the eliminated rows of a 30 x 30 Hilbert matrix overflow
+        // 128 bit numerators and denominators
+        let a: Matrix<Rational> = Matrix::new(
+            &(1..=30)
+                .map(|i| (1..=30).map(|j| Rational::new(1, i + j - 1)).collect())
+                .collect(),
+        );
+        let x: Vector<Rational> = Vector::new(
+            (0..30).map(|i| Rational::new(i - 15, i + 1)).collect(),
+            Shape::Col,
+        );
+        let b: Vector<Rational> = a.vector_product(&x).unwrap();
+        assert_eq!(
+            GaussianElimination::solve(a, b).unwrap().elements(),
+            x.elements()
+        );
+    }
+
+    #[test]
+    fn test_solve_integer_truncates() {
+        // integer division loses the fractional part, Rational keeps it
+        let a: Matrix<i32> = Matrix::new(&vec![vec![2, 1], vec![1, 3]]);
+        let b: Vector<i32> = Vector::new(vec![1, 2], Shape::Col);
+        assert_ne!(
+            GaussianElimination::solve(a, b).unwrap().elements(),
+            &[0, 1]
+        );
+
+        let a: Matrix<Rational> = Matrix::new(&vec![
+            vec![Rational::from(2), Rational::from(1)],
+            vec![Rational::from(1), Rational::from(3)],
+        ]);
+        let b: Vector<Rational> =
+            Vector::new(vec![Rational::from(1), Rational::from(2)], Shape::Col);
+        assert_eq!(
+            GaussianElimination::solve(a, b).unwrap().elements(),
+            &[Rational::new(1, 5), Rational::new(3, 5)]
+        );
+    }
+
+    #[test]
+    fn test_solve_complex() {
+        // the first pivot has to come from the second row, |3 - 4i| > |i|
+        let a: Matrix<Complex<f64>> = Matrix::new(&vec![
+            vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)],
+            vec![Complex::new(3.0, -4.0), Complex::new(1.0, 1.0)],
+        ]);
+        let x: Vector<Complex<f64>> = Vector::new(
+            vec![Complex::new(1.0, -1.0), Complex::new(0.5, 2.0)],
+            Shape::Col,
+        );
+        let b: Vector<Complex<f64>> = a.vector_product(&x).unwrap();
+        let solution: Vector<Complex<f64>> = GaussianElimination::solve(a, b).unwrap();
+
+        for i in 0..2 {
+            let error: Complex<f64> = *solution.get(i).unwrap() - *x.get(i).unwrap();
+            assert!(error.norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_solve_singular() {
+        let a: Matrix<f64> = Matrix::new(&vec![
+            vec![1.0, 2.0, 3.0],
+            vec![2.0, 4.0, 6.0],
+            vec![1.0, 0.0, 1.0],
+        ]);
+        let b: Vector<f64> = Vector::new(vec![1.0, 2.0, 3.0], Shape::Col);
+
+        assert!(a.rank(1e-10) < a.size().rows());
+        assert_eq!(
+            GaussianElimination::solve(a, b).err(),
+            Some(LinearError::SingularMatrix { pivot: 2 })
+        );
+    }
+
+    #[test]
+    fn test_solve_dimension_mismatch() {
+        let a: Matrix<f64> = Matrix::new(&vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let b: Vector<f64> = Vector::new(vec![1.0, 2.0, 3.0], Shape::Col);
+
+        assert_eq!(
+            GaussianElimination::solve(a, b).err(),
+            Some(LinearError::DimensionMismatch {
+                left: Size::new(2, 2),
+                right: Size::new(3, 1)
+            })
+        );
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
     fn test_solve_1() {
         let a: Matrix<f64> = Matrix::new(&vec![
             vec![1.00, 0.00, 0.00, 0.00, 0.00, 0.00],
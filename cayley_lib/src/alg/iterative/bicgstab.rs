@@ -0,0 +1,176 @@
+use linear::{error::LinearError, vector::vector::Vector};
+
+use super::{
+    dot, is_converged, norm, operator::LinearOperator, operator_residual,
+    preconditioner::Preconditioner, prepare_operator, threshold, IterativeSolution,
+};
+
+pub struct BiCgStab {}
+
+impl BiCgStab {
+    // Right preconditioned BiCGSTAB for general non-singular A, two operator and
+    // two preconditioner applications per iteration.
+    // On a breakdown (rho or omega vanishing before convergence) or at max_iterations the
+    // last iterate is returned with a report that says it has not converged.
+    pub fn solve<A, P>(
+        a: &A,
+        b: &Vector<f64>,
+        x0: &Vector<f64>,
+        preconditioner: &P,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> Result<IterativeSolution, LinearError>
+    where
+        A: LinearOperator,
+        P: Preconditioner,
+    {
+        let (b, mut x) = prepare_operator(a, b, x0)?;
+        let n: usize = b.len();
+        let threshold: f64 = threshold(&b, tolerance);
+
+        let mut r: Vec<f64> = operator_residual(a, &b, &x);
+        let r_hat: Vec<f64> = r.clone();
+        let mut p: Vec<f64> = vec![0.0; n];
+        let mut v: Vec<f64> = vec![0.0; n];
+        let (mut rho, mut alpha, mut omega): (f64, f64, f64) = (1.0, 1.0, 1.0);
+
+        let mut residuals: Vec<f64> = vec![norm(&r)];
+        while !is_converged(residuals[residuals.len() - 1], threshold) {
+            let k: usize = residuals.len() - 1;
+            let rho_next: f64 = dot(&r_hat, &r);
+            if k == max_iterations || rho_next == 0.0 || omega == 0.0 {
+                break;
+            }
+
+            let beta: f64 = (rho_next / rho) * (alpha / omega);
+            rho = rho_next;
+            for i in 0..n {
+                p[i] = r[i] + beta * (p[i] - omega * v[i]);
+            }
+            let p_hat: Vec<f64> = preconditioner.apply(&p);
+            v = a.apply(&p_hat);
+            alpha = rho / dot(&r_hat, &v);
+
+            let s: Vec<f64> = r.iter().zip(&v).map(|(r, v)| r - alpha * v).collect();
+            if is_converged(norm(&s), threshold) {
+                for (x_i, p_i) in x.iter_mut().zip(&p_hat) {
+                    *x_i += alpha * p_i;
+                }
+                residuals.push(norm(&s));
+                break;
+            }
+
+            let s_hat: Vec<f64> = preconditioner.apply(&s);
+            let t: Vec<f64> = a.apply(&s_hat);
+            omega = dot(&t, &s) / dot(&t, &t);
+            for i in 0..n {
+                x[i] += alpha * p_hat[i] + omega * s_hat[i];
+                r[i] = s[i] - omega * t[i];
+            }
+            residuals.push(norm(&r));
+        }
+
+        Ok(IterativeSolution::from_residuals(x, residuals, threshold))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use linear::{
+        error::LinearError,
+        matrix::matrix::Matrix,
+        sparse::csr::CsrMatrix,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::BiCgStab;
+    use crate::alg::iterative::{
+        preconditioner::{IdentityPreconditioner, IluPreconditioner, JacobiPreconditioner},
+        IterativeSolution,
+    };
+
+    #[test]
+    fn test_solve() {
+        let a: Matrix<f64> = get_default_matrix();
+        let b: Vector<f64> = Vector::new(vec![1.0, 0.0, -2.0, 3.0, 1.0], Shape::Col);
+        let x0: Vector<f64> = Vector::new(vec![0.0; 5], Shape::Col);
+
+        let plain: IterativeSolution =
+            BiCgStab::solve(&a, &b, &x0, &IdentityPreconditioner {}, 1e-12, 100).unwrap();
+        let jacobi: JacobiPreconditioner =
+            JacobiPreconditioner::new(&CsrMatrix::from_matrix(&a)).unwrap();
+        let preconditioned: IterativeSolution =
+            BiCgStab::solve(&a, &b, &x0, &jacobi, 1e-12, 100).unwrap();
+
+        assert_residual(&a, &b, plain.x());
+        assert_residual(&a, &b, preconditioned.x());
+    }
+
+    #[test]
+    fn test_ilu_preconditioned() {
+        let a: Matrix<f64> = get_default_matrix();
+        let b: Vector<f64> = Vector::new(vec![1.0, 0.0, -2.0, 3.0, 1.0], Shape::Col);
+        let x0: Vector<f64> = Vector::new(vec![0.0; 5], Shape::Col);
+        let ilu: IluPreconditioner = IluPreconditioner::new(&CsrMatrix::from_matrix(&a)).unwrap();
+        let solution: IterativeSolution = BiCgStab::solve(&a, &b, &x0, &ilu, 1e-12, 100).unwrap();
+
+        assert_eq!(solution.report().iterations(), 1);
+        assert_residual(&a, &b, solution.x());
+    }
+
+    #[test]
+    fn test_not_converged() {
+        let a: Matrix<f64> = get_default_matrix();
+        let b: Vector<f64> = Vector::new(vec![1.0, 0.0, -2.0, 3.0, 1.0], Shape::Col);
+        let x0: Vector<f64> = Vector::new(vec![0.0; 5], Shape::Col);
+        let solution: IterativeSolution =
+            BiCgStab::solve(&a, &b, &x0, &IdentityPreconditioner {}, 1e-12, 1).unwrap();
+
+        assert!(!solution.report().converged());
+        assert_eq!(solution.report().iterations(), 1);
+        assert!(solution.report().final_residual() < solution.report().residuals()[0]);
+    }
+
+    #[test]
+    fn test_breakdown() {
+        // s = [0, 1] after the first half step and A * s is orthogonal to it, so omega = 0
+        let a: Matrix<f64> = Matrix::new(&vec![vec![1.0, 1.0], vec![-1.0, 0.0]]);
+        let b: Vector<f64> = Vector::new(vec![1.0, 0.0], Shape::Col);
+        let x0: Vector<f64> = Vector::new(vec![0.0; 2], Shape::Col);
+        let solution: IterativeSolution =
+            BiCgStab::solve(&a, &b, &x0, &IdentityPreconditioner {}, 1e-12, 100).unwrap();
+
+        assert!(!solution.report().converged());
+        assert_eq!(solution.report().iterations(), 1);
+        assert_eq!(solution.x().elements(), &[1.0, 0.0]);
+        assert_eq!(solution.report().final_residual(), 1.0);
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let a: Matrix<f64> = get_default_matrix();
+        let b: Vector<f64> = Vector::new(vec![1.0, 0.0], Shape::Col);
+        assert!(matches!(
+            BiCgStab::solve(&a, &b, &b, &IdentityPreconditioner {}, 1e-12, 100).err(),
+            Some(LinearError::DimensionMismatch { .. })
+        ));
+    }
+
+    fn assert_residual(a: &Matrix<f64>, b: &Vector<f64>, x: &Vector<f64>) {
+        let ax: Vector<f64> = a.vector_product(x).unwrap();
+        for i in 0..b.size() {
+            assert!((ax.get(i).unwrap() - b.get(i).unwrap()).abs() < 1e-10);
+        }
+    }
+
+    // non-symmetric tridiagonal matrix
+    fn get_default_matrix() -> Matrix<f64> {
+        Matrix::new(&vec![
+            vec![3.0, -1.0, 0.0, 0.0, 0.0],
+            vec![-2.0, 3.0, -1.0, 0.0, 0.0],
+            vec![0.0, -2.0, 3.0, -1.0, 0.0],
+            vec![0.0, 0.0, -2.0, 3.0, -1.0],
+            vec![0.0, 0.0, 0.0, -2.0, 3.0],
+        ])
+    }
+}
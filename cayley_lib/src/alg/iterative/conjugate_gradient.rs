@@ -0,0 +1,158 @@
+use linear::{error::LinearError, matrix::matrix::Matrix, vector::vector::Vector};
+
+use super::{dot, is_converged, norm, prepare, residual, threshold, IterativeSolution};
+
+pub struct ConjugateGradient {}
+
+impl ConjugateGradient {
+    // Conjugate gradient for a symmetric positive definite A, in exact arithmetic
+    // it terminates after at most n iterations. Stopping at max_iterations is not an
+    // error, the report of the returned solution says it has not converged.
+    // The loop tracks the recursively updated residual, which drifts from b - A * x
+    // in floating point, so the last reported residual is recomputed from x.
+    // Fails with NotPositiveDefinite { pivot: k } when the k-th search direction
+    // has non-positive curvature p^T * A * p.
+    pub fn solve(
+        a: &Matrix<f64>,
+        b: &Vector<f64>,
+        x0: &Vector<f64>,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> Result<IterativeSolution, LinearError> {
+        let (a, b, mut x) = prepare(a, b, x0)?;
+        let n: usize = a.len();
+        for (i, row) in a.iter().enumerate() {
+            for (j, &upper) in row.iter().enumerate().skip(i + 1) {
+                let lower: f64 = a[j][i];
+                if (upper - lower).abs() > 1e-12 * upper.abs().max(lower.abs()).max(1.0) {
+                    return Err(LinearError::NotSymmetric);
+                }
+            }
+        }
+
+        let threshold: f64 = threshold(&b, tolerance);
+        let mut r: Vec<f64> = residual(&a, &b, &x);
+        let mut p: Vec<f64> = r.clone();
+        let mut rr: f64 = dot(&r, &r);
+        let mut residuals: Vec<f64> = vec![rr.sqrt()];
+
+        while !is_converged(residuals[residuals.len() - 1], threshold) {
+            let k: usize = residuals.len() - 1;
+            if k == max_iterations {
+                break;
+            }
+
+            let ap: Vec<f64> = a.iter().map(|row| dot(row, &p)).collect();
+            let curvature: f64 = dot(&p, &ap);
+            if curvature <= 0.0 {
+                return Err(LinearError::NotPositiveDefinite { pivot: k });
+            }
+
+            let alpha: f64 = rr / curvature;
+            for i in 0..n {
+                x[i] += alpha * p[i];
+                r[i] -= alpha * ap[i];
+            }
+
+            let rr_next: f64 = dot(&r, &r);
+            let beta: f64 = rr_next / rr;
+            for i in 0..n {
+                p[i] = r[i] + beta * p[i];
+            }
+            rr = rr_next;
+            residuals.push(rr.sqrt());
+        }
+
+        let last: usize = residuals.len() - 1;
+        residuals[last] = norm(&residual(&a, &b, &x));
+        Ok(IterativeSolution::from_residuals(x, residuals, threshold))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use linear::{
+        error::LinearError,
+        matrix::matrix::Matrix,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::ConjugateGradient;
+    use crate::alg::iterative::IterativeSolution;
+
+    #[test]
+    fn test_solve() {
+        let a: Matrix<f64> = get_default_matrix();
+        let b: Vector<f64> = Vector::new(vec![1.0, 2.0, 3.0], Shape::Col);
+        let x0: Vector<f64> = Vector::new(vec![0.0; 3], Shape::Col);
+        let solution: IterativeSolution = ConjugateGradient::solve(&a, &b, &x0, 1e-10, 10).unwrap();
+        let ax: Vector<f64> = a.vector_product(solution.x()).unwrap();
+
+        for i in 0..b.size() {
+            assert!((b.get(i).unwrap() - ax.get(i).unwrap()).abs() < 1e-8);
+        }
+        assert!(solution.report().iterations() <= 4);
+        assert!(solution.report().converged());
+    }
+
+    #[test]
+    fn test_not_converged() {
+        let a: Matrix<f64> = get_default_matrix();
+        let b: Vector<f64> = Vector::new(vec![1.0, 2.0, 3.0], Shape::Col);
+        let x0: Vector<f64> = Vector::new(vec![0.0; 3], Shape::Col);
+        let solution: IterativeSolution = ConjugateGradient::solve(&a, &b, &x0, 1e-10, 1).unwrap();
+
+        // one step is a line search along b, the last iterate is kept and its
+        // residual is the last one reported
+        let ax: Vector<f64> = a.vector_product(solution.x()).unwrap();
+        let r: Vec<f64> = (0..3)
+            .map(|i| b.get(i).unwrap() - ax.get(i).unwrap())
+            .collect();
+        let r_norm: f64 = r.iter().map(|e| e * e).sum::<f64>().sqrt();
+
+        assert!(!solution.report().converged());
+        assert_eq!(solution.report().iterations(), 1);
+        assert!(solution.x().elements().iter().any(|&e| e != 0.0));
+        assert!((solution.report().final_residual() - r_norm).abs() < 1e-8 * r_norm);
+    }
+
+    #[test]
+    fn test_initial_guess_is_solution() {
+        let a: Matrix<f64> = get_default_matrix();
+        let x: Vector<f64> = Vector::new(vec![1.0, -1.0, 0.5], Shape::Col);
+        let b: Vector<f64> = a.vector_product(&x).unwrap();
+        let solution: IterativeSolution = ConjugateGradient::solve(&a, &b, &x, 1e-10, 10).unwrap();
+
+        assert_eq!(solution.report().iterations(), 0);
+        assert_eq!(solution.report().final_residual(), 0.0);
+    }
+
+    #[test]
+    fn test_not_positive_definite() {
+        let a: Matrix<f64> = Matrix::new(&vec![vec![1.0, 0.0], vec![0.0, -1.0]]);
+        let b: Vector<f64> = Vector::new(vec![0.0, 1.0], Shape::Col);
+        let x0: Vector<f64> = Vector::new(vec![0.0, 0.0], Shape::Col);
+        assert_eq!(
+            ConjugateGradient::solve(&a, &b, &x0, 1e-10, 10).err(),
+            Some(LinearError::NotPositiveDefinite { pivot: 0 })
+        );
+    }
+
+    #[test]
+    fn test_not_symmetric() {
+        let a: Matrix<f64> = Matrix::new(&vec![vec![4.0, 1.0], vec![2.0, 3.0]]);
+        let b: Vector<f64> = Vector::new(vec![1.0, 1.0], Shape::Col);
+        assert_eq!(
+            ConjugateGradient::solve(&a, &b, &b, 1e-10, 10).err(),
+            Some(LinearError::NotSymmetric)
+        );
+    }
+
+    fn get_default_matrix() -> Matrix<f64> {
+        Matrix::new(&vec![
+            vec![4.0, 12.0, -16.0],
+            vec![12.0, 37.0, -43.0],
+            vec![-16.0, -43.0, 98.0],
+        ])
+    }
+}
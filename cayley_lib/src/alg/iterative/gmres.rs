@@ -0,0 +1,276 @@
+use linear::{error::LinearError, vector::vector::Vector};
+
+use super::{
+    dot, is_converged, norm, operator::LinearOperator, operator_residual,
+    preconditioner::Preconditioner, prepare_operator, threshold, IterativeSolution,
+};
+
+pub struct Gmres {}
+
+impl Gmres {
+    // Restarted GMRES(restart) with right preconditioning: minimizes ||b - A * x||_2 over
+    // x0 + M^-1 * K_m(A * M^-1, r0), restarting from the current iterate every restart steps.
+    // Every Arnoldi step counts as one iteration, the residual history holds the least squares
+    // estimate after each step and the true residual at the end of each cycle.
+    // At max_iterations the partial cycle is applied and the unconverged iterate returned,
+    // the same happens on a breakdown where the next Givens rotation is undefined.
+    pub fn solve<A, P>(
+        a: &A,
+        b: &Vector<f64>,
+        x0: &Vector<f64>,
+        preconditioner: &P,
+        restart: usize,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> Result<IterativeSolution, LinearError>
+    where
+        A: LinearOperator,
+        P: Preconditioner,
+    {
+        if restart == 0 {
+            return Err(LinearError::InvalidArgument {
+                name: "restart",
+                message: "must be positive",
+            });
+        }
+
+        let (b, mut x) = prepare_operator(a, b, x0)?;
+        let n: usize = b.len();
+        let threshold: f64 = threshold(&b, tolerance);
+
+        let mut r: Vec<f64> = operator_residual(a, &b, &x);
+        let mut residuals: Vec<f64> = vec![norm(&r)];
+        let mut stopped: bool = false;
+        while !stopped && !is_converged(residuals[residuals.len() - 1], threshold) {
+            let beta: f64 = residuals[residuals.len() - 1];
+
+            // Arnoldi basis v, Hessenberg matrix h reduced to upper triangular form by the
+            // Givens rotations (cs, sn), g is the rotated right hand side beta * e1
+            let mut v: Vec<Vec<f64>> = vec![r.iter().map(|e| e / beta).collect()];
+            let mut h: Vec<Vec<f64>> = vec![vec![0.0; restart]; restart + 1];
+            let mut cs: Vec<f64> = vec![0.0; restart];
+            let mut sn: Vec<f64> = vec![0.0; restart];
+            let mut g: Vec<f64> = vec![0.0; restart + 1];
+            g[0] = beta;
+
+            let mut k: usize = 0;
+            while k < restart {
+                // out of iterations, the steps of this cycle are still applied below
+                if residuals.len() > max_iterations {
+                    stopped = true;
+                    break;
+                }
+
+                let mut w: Vec<f64> = a.apply(&preconditioner.apply(&v[k]));
+                for i in 0..=k {
+                    h[i][k] = dot(&w, &v[i]);
+                    for (w_j, v_j) in w.iter_mut().zip(&v[i]) {
+                        *w_j -= h[i][k] * v_j;
+                    }
+                }
+                let w_norm: f64 = norm(&w);
+                h[k + 1][k] = w_norm;
+
+                for i in 0..k {
+                    let (upper, lower) = (h[i][k], h[i + 1][k]);
+                    h[i][k] = cs[i] * upper + sn[i] * lower;
+                    h[i + 1][k] = -sn[i] * upper + cs[i] * lower;
+                }
+                let d: f64 = h[k][k].hypot(h[k + 1][k]);
+                // the new column is zero after the previous rotations, H is singular and
+                // this step cannot reduce the residual
+                if d == 0.0 {
+                    stopped = true;
+                    break;
+                }
+                cs[k] = h[k][k] / d;
+                sn[k] = h[k + 1][k] / d;
+                h[k][k] = d;
+                h[k + 1][k] = 0.0;
+                g[k + 1] = -sn[k] * g[k];
+                g[k] *= cs[k];
+
+                k += 1;
+                residuals.push(g[k].abs());
+                // w = 0 means the Krylov space is invariant and the solution is in it
+                if w_norm == 0.0 || is_converged(g[k].abs(), threshold) {
+                    break;
+                }
+                v.push(w.iter().map(|e| e / w_norm).collect());
+            }
+
+            // y = H^-1 * g, x += M^-1 * V * y
+            let mut y: Vec<f64> = g[..k].to_vec();
+            for i in (0..k).rev() {
+                for j in (i + 1)..k {
+                    y[i] -= h[i][j] * y[j];
+                }
+                y[i] /= h[i][i];
+            }
+            let mut update: Vec<f64> = vec![0.0; n];
+            for (v_i, y_i) in v.iter().zip(&y) {
+                for (u, e) in update.iter_mut().zip(v_i) {
+                    *u += y_i * e;
+                }
+            }
+            for (x_i, u) in x.iter_mut().zip(preconditioner.apply(&update)) {
+                *x_i += u;
+            }
+
+            r = operator_residual(a, &b, &x);
+            let last: usize = residuals.len() - 1;
+            residuals[last] = norm(&r);
+        }
+
+        Ok(IterativeSolution::from_residuals(x, residuals, threshold))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use linear::{
+        error::LinearError,
+        matrix::{matrix::Matrix, size::Size},
+        sparse::csr::CsrMatrix,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::Gmres;
+    use crate::alg::iterative::{
+        operator::LinearOperator,
+        preconditioner::{IdentityPreconditioner, IluPreconditioner, JacobiPreconditioner},
+        ConvergenceReport, IterativeSolution,
+    };
+
+    // upwind discretization of -u'' + c * u' on n interior points, stored as a stencil only
+    struct ConvectionDiffusion {
+        n: usize,
+        c: f64,
+    }
+
+    impl LinearOperator for ConvectionDiffusion {
+        fn size(&self) -> Size {
+            Size::new(self.n, self.n)
+        }
+
+        fn apply(&self, x: &[f64]) -> Vec<f64> {
+            (0..self.n)
+                .map(|i| {
+                    let left: f64 = if i > 0 { x[i - 1] } else { 0.0 };
+                    let right: f64 = if i + 1 < self.n { x[i + 1] } else { 0.0 };
+                    (2.0 + self.c) * x[i] - (1.0 + self.c) * left - right
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_matrix_free() {
+        let a: ConvectionDiffusion = ConvectionDiffusion { n: 50, c: 0.5 };
+        let b: Vector<f64> = Vector::new(vec![1.0; 50], Shape::Col);
+        let x0: Vector<f64> = Vector::new(vec![0.0; 50], Shape::Col);
+        let solution: IterativeSolution =
+            Gmres::solve(&a, &b, &x0, &IdentityPreconditioner {}, 20, 1e-10, 500).unwrap();
+
+        let ax: Vec<f64> = a.apply(solution.x().elements());
+        for (ax_i, b_i) in ax.iter().zip(b.elements()) {
+            assert!((ax_i - b_i).abs() < 1e-8);
+        }
+        assert_eq!(
+            solution.report().residuals().len(),
+            solution.report().iterations() + 1
+        );
+    }
+
+    #[test]
+    fn test_full_gmres_terminates() {
+        let a: Matrix<f64> = get_default_matrix();
+        let b: Vector<f64> = Vector::new(vec![1.0, 2.0, 3.0, 4.0], Shape::Col);
+        let x0: Vector<f64> = Vector::new(vec![0.0; 4], Shape::Col);
+        let jacobi: JacobiPreconditioner =
+            JacobiPreconditioner::new(&CsrMatrix::from_matrix(&a)).unwrap();
+        let solution: IterativeSolution = Gmres::solve(&a, &b, &x0, &jacobi, 4, 1e-12, 10).unwrap();
+
+        assert!(solution.report().iterations() <= 4);
+        assert_residual(&a, &b, solution.x());
+    }
+
+    #[test]
+    fn test_ilu_preconditioned() {
+        // ILU(0) is the exact LU of a tridiagonal matrix, so one step suffices
+        let a: Matrix<f64> = get_default_matrix();
+        let b: Vector<f64> = Vector::new(vec![1.0, 2.0, 3.0, 4.0], Shape::Col);
+        let x0: Vector<f64> = Vector::new(vec![0.0; 4], Shape::Col);
+        let ilu: IluPreconditioner = IluPreconditioner::new(&CsrMatrix::from_matrix(&a)).unwrap();
+        let solution: IterativeSolution = Gmres::solve(&a, &b, &x0, &ilu, 4, 1e-12, 10).unwrap();
+
+        assert_eq!(solution.report().iterations(), 1);
+        assert_residual(&a, &b, solution.x());
+    }
+
+    #[test]
+    fn test_not_converged() {
+        let a: ConvectionDiffusion = ConvectionDiffusion { n: 50, c: 0.5 };
+        let b: Vector<f64> = Vector::new(vec![1.0; 50], Shape::Col);
+        let solution: IterativeSolution =
+            Gmres::solve(&a, &b, &b, &IdentityPreconditioner {}, 5, 1e-12, 7).unwrap();
+        let report: &ConvergenceReport = solution.report();
+
+        // one full cycle of 5 steps and 2 steps of the next, both applied to x
+        assert!(!report.converged());
+        assert_eq!(report.iterations(), 7);
+        assert!(report.final_residual() < report.residuals()[5]);
+        let r: Vec<f64> = b
+            .elements()
+            .iter()
+            .zip(a.apply(solution.x().elements()))
+            .map(|(b_i, ax_i)| b_i - ax_i)
+            .collect();
+        let r_norm: f64 = r.iter().map(|e| e * e).sum::<f64>().sqrt();
+        assert!((report.final_residual() - r_norm).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_zero_restart() {
+        let a: Matrix<f64> = get_default_matrix();
+        let b: Vector<f64> = Vector::new(vec![1.0, 2.0, 3.0, 4.0], Shape::Col);
+        assert_eq!(
+            Gmres::solve(&a, &b, &b, &IdentityPreconditioner {}, 0, 1e-12, 10).err(),
+            Some(LinearError::InvalidArgument {
+                name: "restart",
+                message: "must be positive"
+            })
+        );
+    }
+
+    #[test]
+    fn test_breakdown() {
+        // A * b = 0, the first Hessenberg column is zero and there is no rotation for it
+        let a: Matrix<f64> = Matrix::new(&vec![vec![0.0, 1.0], vec![0.0, 0.0]]);
+        let b: Vector<f64> = Vector::new(vec![1.0, 0.0], Shape::Col);
+        let x0: Vector<f64> = Vector::new(vec![0.0; 2], Shape::Col);
+        let solution: IterativeSolution =
+            Gmres::solve(&a, &b, &x0, &IdentityPreconditioner {}, 2, 1e-12, 100).unwrap();
+
+        assert!(!solution.report().converged());
+        assert_eq!(solution.report().iterations(), 0);
+        assert_eq!(solution.x().elements(), &[0.0, 0.0]);
+        assert_eq!(solution.report().final_residual(), 1.0);
+    }
+
+    fn assert_residual(a: &Matrix<f64>, b: &Vector<f64>, x: &Vector<f64>) {
+        let ax: Vector<f64> = a.vector_product(x).unwrap();
+        for i in 0..b.size() {
+            assert!((ax.get(i).unwrap() - b.get(i).unwrap()).abs() < 1e-10);
+        }
+    }
+
+    fn get_default_matrix() -> Matrix<f64> {
+        Matrix::new(&vec![
+            vec![4.0, -1.0, 0.0, 0.0],
+            vec![-2.0, 4.0, -1.0, 0.0],
+            vec![0.0, -2.0, 4.0, -1.0],
+            vec![0.0, 0.0, -2.0, 4.0],
+        ])
+    }
+}
@@ -0,0 +1,141 @@
+pub mod bicgstab;
+pub mod conjugate_gradient;
+pub mod gmres;
+pub mod operator;
+pub mod preconditioner;
+pub mod stationary;
+
+use linear::{
+    error::LinearError,
+    matrix::{matrix::Matrix, size::Size},
+    vector::{shape::Shape, vector::Vector},
+};
+
+use self::operator::LinearOperator;
+
+// a as rows, b and the initial guess
+type System = (Vec<Vec<f64>>, Vec<f64>, Vec<f64>);
+
+// Last iterate of an iterative solver together with how it got there. Solvers return
+// one whether or not they converged, check report().converged() before trusting x.
+pub struct IterativeSolution {
+    x: Vector<f64>,
+    report: ConvergenceReport,
+}
+
+pub struct ConvergenceReport {
+    iterations: usize,
+    residuals: Vec<f64>,
+    converged: bool,
+}
+
+impl IterativeSolution {
+    // converged if the last residual is within threshold
+    fn from_residuals(x: Vec<f64>, residuals: Vec<f64>, threshold: f64) -> Self {
+        IterativeSolution {
+            x: Vector::new(x, Shape::Col),
+            report: ConvergenceReport {
+                iterations: residuals.len() - 1,
+                converged: is_converged(residuals[residuals.len() - 1], threshold),
+                residuals,
+            },
+        }
+    }
+
+    pub fn x(&self) -> &Vector<f64> {
+        &self.x
+    }
+
+    pub fn report(&self) -> &ConvergenceReport {
+        &self.report
+    }
+}
+
+impl ConvergenceReport {
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    // ||b - A * x_k||_2 for k = 0..=iterations, the first entry belongs to the initial guess
+    pub fn residuals(&self) -> &[f64] {
+        &self.residuals
+    }
+
+    pub fn final_residual(&self) -> f64 {
+        self.residuals[self.iterations]
+    }
+
+    // false if the solver stopped at max_iterations or broke down before reaching the
+    // tolerance, the solution then holds the last iterate
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+}
+
+fn prepare(a: &Matrix<f64>, b: &Vector<f64>, x0: &Vector<f64>) -> Result<System, LinearError> {
+    let (b, x) = prepare_operator(a, b, x0)?;
+    let rows: Vec<Vec<f64>> = (0..b.len())
+        .map(|r| a.get_row(r).iter().map(|e| **e).collect())
+        .collect();
+    Ok((rows, b, x))
+}
+
+// checks that a is square and matches b and x0, returns b and the initial guess
+fn prepare_operator<A: LinearOperator>(
+    a: &A,
+    b: &Vector<f64>,
+    x0: &Vector<f64>,
+) -> Result<(Vec<f64>, Vec<f64>), LinearError> {
+    let size: Size = a.size();
+    if size.rows() != size.cols() {
+        return Err(LinearError::NotSquare { size });
+    }
+    for v in [b, x0] {
+        if v.size() != size.rows() {
+            return Err(LinearError::DimensionMismatch {
+                left: size,
+                right: v.dimensions(),
+            });
+        }
+    }
+
+    Ok((b.elements().to_vec(), x0.elements().to_vec()))
+}
+
+// Iteration stops once ||b - A * x||_2 <= tolerance * ||b||_2,
+// for b = 0 the tolerance is taken as an absolute bound.
+fn threshold(b: &[f64], tolerance: f64) -> f64 {
+    let scale: f64 = norm(b);
+    if scale > 0.0 {
+        tolerance * scale
+    } else {
+        tolerance
+    }
+}
+
+// a NaN residual means the iteration blew up, which never counts as converged
+fn is_converged(residual: f64, threshold: f64) -> bool {
+    !residual.is_nan() && residual <= threshold
+}
+
+fn residual(a: &[Vec<f64>], b: &[f64], x: &[f64]) -> Vec<f64> {
+    a.iter()
+        .zip(b)
+        .map(|(row, b_i)| b_i - dot(row, x))
+        .collect()
+}
+
+fn operator_residual<A: LinearOperator>(a: &A, b: &[f64], x: &[f64]) -> Vec<f64> {
+    b.iter()
+        .zip(a.apply(x))
+        .map(|(b_i, ax_i)| b_i - ax_i)
+        .collect()
+}
+
+fn dot(u: &[f64], v: &[f64]) -> f64 {
+    u.iter().zip(v).map(|(a, b)| a * b).sum()
+}
+
+fn norm(v: &[f64]) -> f64 {
+    dot(v, v).sqrt()
+}
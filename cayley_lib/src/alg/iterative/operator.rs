@@ -0,0 +1,77 @@
+use linear::{
+    matrix::{matrix::Matrix, size::Size},
+    sparse::csr::CsrMatrix,
+};
+
+// Anything that can compute y = A * x, so Krylov solvers can run on dense matrices
+// as well as on operators that never store A (stencils, sparse formats, ...).
+pub trait LinearOperator {
+    fn size(&self) -> Size;
+    fn apply(&self, x: &[f64]) -> Vec<f64>;
+}
+
+impl LinearOperator for Matrix<f64> {
+    fn size(&self) -> Size {
+        Matrix::size(self).clone()
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        (0..Matrix::size(self).rows())
+            .map(|r| self.get_row(r).iter().zip(x).map(|(a, b)| **a * b).sum())
+            .collect()
+    }
+}
+
+impl LinearOperator for CsrMatrix<f64> {
+    fn size(&self) -> Size {
+        CsrMatrix::size(self).clone()
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        let offsets: &[usize] = self.row_offsets();
+        (0..CsrMatrix::size(self).rows())
+            .map(|r| {
+                (offsets[r]..offsets[r + 1])
+                    .map(|k| self.values()[k] * x[self.col_indices()[k]])
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use linear::{
+        sparse::{coo::CooMatrix, csr::CsrMatrix},
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::LinearOperator;
+    use crate::alg::iterative::{
+        bicgstab::BiCgStab, preconditioner::IdentityPreconditioner, IterativeSolution,
+    };
+
+    #[test]
+    fn test_sparse_operator() {
+        // tridiag(-1, 4, -1) with 10000 unknowns, far too large to store densely
+        let n: usize = 10_000;
+        let mut coo: CooMatrix<f64> = CooMatrix::new(n, n);
+        for i in 0..n {
+            coo.push(i, i, 4.0).unwrap();
+            if i > 0 {
+                coo.push(i, i - 1, -1.0).unwrap();
+                coo.push(i - 1, i, -1.0).unwrap();
+            }
+        }
+        let a: CsrMatrix<f64> = coo.to_csr();
+        let b: Vector<f64> = Vector::new(vec![1.0; n], Shape::Col);
+        let x0: Vector<f64> = Vector::new(vec![0.0; n], Shape::Col);
+        let solution: IterativeSolution =
+            BiCgStab::solve(&a, &b, &x0, &IdentityPreconditioner {}, 1e-10, 100).unwrap();
+
+        let ax: Vec<f64> = a.apply(solution.x().elements());
+        for (ax_i, b_i) in ax.iter().zip(b.elements()) {
+            assert!((ax_i - b_i).abs() < 1e-8);
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use linear::{error::LinearError, sparse::csr::CsrMatrix};
+
+// Approximation M of A that is cheap to invert, apply returns z = M^-1 * r.
+pub trait Preconditioner {
+    fn apply(&self, r: &[f64]) -> Vec<f64>;
+}
+
+// M = I, runs a solver unpreconditioned
+pub struct IdentityPreconditioner {}
+
+// M = diag(A)
+pub struct JacobiPreconditioner {
+    inverse_diagonal: Vec<f64>,
+}
+
+// M = L * U where L and U are computed by Gaussian elimination restricted to the
+// nonzero pattern of A (ILU(0)). Both factors share the CSR layout of A, L has a unit
+// diagonal and is stored left of it, U from the diagonal on.
+pub struct IluPreconditioner {
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<f64>,
+    // position of the diagonal element of every row in values
+    diagonal: Vec<usize>,
+}
+
+impl Preconditioner for IdentityPreconditioner {
+    fn apply(&self, r: &[f64]) -> Vec<f64> {
+        r.to_vec()
+    }
+}
+
+impl JacobiPreconditioner {
+    pub fn new(a: &CsrMatrix<f64>) -> Result<Self, LinearError> {
+        let n: usize = a.size().rows();
+        if n != a.size().cols() {
+            return Err(LinearError::NotSquare {
+                size: a.size().clone(),
+            });
+        }
+
+        let mut inverse_diagonal: Vec<f64> = vec![0.0; n];
+        for (i, d) in inverse_diagonal.iter_mut().enumerate() {
+            let diagonal: f64 = a.get(i, i).unwrap();
+            if diagonal == 0.0 {
+                return Err(LinearError::SingularMatrix { pivot: i });
+            }
+            *d = 1.0 / diagonal;
+        }
+
+        Ok(JacobiPreconditioner { inverse_diagonal })
+    }
+}
+
+impl Preconditioner for JacobiPreconditioner {
+    fn apply(&self, r: &[f64]) -> Vec<f64> {
+        r.iter()
+            .zip(&self.inverse_diagonal)
+            .map(|(r, d)| r * d)
+            .collect()
+    }
+}
+
+impl IluPreconditioner {
+    // Fails with SingularMatrix { pivot: i } when the i-th pivot is zero or not stored.
+    pub fn new(a: &CsrMatrix<f64>) -> Result<Self, LinearError> {
+        let n: usize = a.size().rows();
+        if n != a.size().cols() {
+            return Err(LinearError::NotSquare {
+                size: a.size().clone(),
+            });
+        }
+
+        let row_offsets: Vec<usize> = a.row_offsets().to_vec();
+        let col_indices: Vec<usize> = a.col_indices().to_vec();
+        let mut values: Vec<f64> = a.values().to_vec();
+        let mut diagonal: Vec<usize> = vec![0; n];
+
+        // position of column j in the current row, usize::MAX outside of its pattern
+        let mut position: Vec<usize> = vec![usize::MAX; n];
+        for i in 0..n {
+            let row: std::ops::Range<usize> = row_offsets[i]..row_offsets[i + 1];
+            for p in row.clone() {
+                position[col_indices[p]] = p;
+            }
+
+            // the columns of a row are sorted, so the multipliers are computed left to right
+            for p in row.clone() {
+                let k: usize = col_indices[p];
+                if k >= i {
+                    break;
+                }
+
+                values[p] /= values[diagonal[k]];
+                let multiplier: f64 = values[p];
+                for q in (diagonal[k] + 1)..row_offsets[k + 1] {
+                    let target: usize = position[col_indices[q]];
+                    if target != usize::MAX {
+                        values[target] -= multiplier * values[q];
+                    }
+                }
+            }
+
+            let p: usize = position[i];
+            if p == usize::MAX || values[p] == 0.0 {
+                return Err(LinearError::SingularMatrix { pivot: i });
+            }
+            diagonal[i] = p;
+            for p in row {
+                position[col_indices[p]] = usize::MAX;
+            }
+        }
+
+        Ok(IluPreconditioner {
+            row_offsets,
+            col_indices,
+            values,
+            diagonal,
+        })
+    }
+}
+
+impl Preconditioner for IluPreconditioner {
+    fn apply(&self, r: &[f64]) -> Vec<f64> {
+        let n: usize = self.diagonal.len();
+
+        // forward substitution: L * y = r
+        let mut z: Vec<f64> = r.to_vec();
+        for i in 0..n {
+            for p in self.row_offsets[i]..self.diagonal[i] {
+                z[i] -= self.values[p] * z[self.col_indices[p]];
+            }
+        }
+
+        // back substitution: U * z = y
+        for i in (0..n).rev() {
+            for p in (self.diagonal[i] + 1)..self.row_offsets[i + 1] {
+                z[i] -= self.values[p] * z[self.col_indices[p]];
+            }
+            z[i] /= self.values[self.diagonal[i]];
+        }
+        z
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use linear::{
+        error::LinearError,
+        matrix::matrix::Matrix,
+        sparse::{coo::CooMatrix, csr::CsrMatrix},
+    };
+
+    use super::{IluPreconditioner, JacobiPreconditioner, Preconditioner};
+
+    #[test]
+    fn test_jacobi() {
+        let a: CsrMatrix<f64> =
+            CsrMatrix::from_matrix(&Matrix::new(&vec![vec![2.0, 1.0], vec![1.0, 4.0]]));
+        let preconditioner: JacobiPreconditioner = JacobiPreconditioner::new(&a).unwrap();
+
+        assert_eq!(preconditioner.apply(&[1.0, 1.0]), vec![0.5, 0.25]);
+    }
+
+    #[test]
+    fn test_ilu_is_exact_for_tridiagonal() {
+        // no fill-in happens for a tridiagonal matrix, so ILU(0) equals LU
+        let a: Matrix<f64> = Matrix::new(&vec![
+            vec![4.0, -1.0, 0.0, 0.0],
+            vec![-2.0, 4.0, -1.0, 0.0],
+            vec![0.0, -2.0, 4.0, -1.0],
+            vec![0.0, 0.0, -2.0, 4.0],
+        ]);
+        let preconditioner: IluPreconditioner =
+            IluPreconditioner::new(&CsrMatrix::from_matrix(&a)).unwrap();
+        let x: Vec<f64> = vec![1.0, -2.0, 3.0, 0.5];
+        let b: Vec<f64> = (0..4)
+            .map(|r| (0..4).map(|c| a.get(r, c).unwrap() * x[c]).sum())
+            .collect();
+
+        for (z, e) in preconditioner.apply(&b).iter().zip(&x) {
+            assert!((z - e).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ilu_drops_fill_in() {
+        // LU would fill in (1, 2) and (2, 1), ILU(0) drops both, so L * U differs from A
+        // there by l_10 * u_02 = l_20 * u_01 = 1 / 4
+        let a: Matrix<f64> = Matrix::new(&vec![
+            vec![4.0, 1.0, 1.0],
+            vec![1.0, 4.0, 0.0],
+            vec![1.0, 0.0, 4.0],
+        ]);
+        let preconditioner: IluPreconditioner =
+            IluPreconditioner::new(&CsrMatrix::from_matrix(&a)).unwrap();
+        let m: Matrix<f64> = Matrix::new(&vec![
+            vec![4.0, 1.0, 1.0],
+            vec![1.0, 4.0, 0.25],
+            vec![1.0, 0.25, 4.0],
+        ]);
+        let x: Vec<f64> = vec![1.0, 2.0, 3.0];
+        let mx: Vec<f64> = (0..3)
+            .map(|r| (0..3).map(|c| m.get(r, c).unwrap() * x[c]).sum())
+            .collect();
+
+        for (z, e) in preconditioner.apply(&mx).iter().zip(&x) {
+            assert!((z - e).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_ilu_large_sparse() {
+        // tridiag(-1, 4, -1) with 10000 unknowns, the factor only keeps the 3n - 2 nonzeros
+        let n: usize = 10_000;
+        let mut coo: CooMatrix<f64> = CooMatrix::new(n, n);
+        for i in 0..n {
+            coo.push(i, i, 4.0).unwrap();
+            if i > 0 {
+                coo.push(i, i - 1, -1.0).unwrap();
+                coo.push(i - 1, i, -1.0).unwrap();
+            }
+        }
+        let a: CsrMatrix<f64> = coo.to_csr();
+        let preconditioner: IluPreconditioner = IluPreconditioner::new(&a).unwrap();
+        let x: Vec<f64> = (0..n).map(|i| (i % 7) as f64 - 3.0).collect();
+        let b: Vec<f64> = (0..n)
+            .map(|i| {
+                let left: f64 = if i > 0 { x[i - 1] } else { 0.0 };
+                let right: f64 = if i + 1 < n { x[i + 1] } else { 0.0 };
+                4.0 * x[i] - left - right
+            })
+            .collect();
+
+        for (z, e) in preconditioner.apply(&b).iter().zip(&x) {
+            assert!((z - e).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_zero_pivot() {
+        // the zero pivot is not even stored
+        let a: CsrMatrix<f64> =
+            CsrMatrix::from_matrix(&Matrix::new(&vec![vec![0.0, 1.0], vec![1.0, 1.0]]));
+
+        assert_eq!(
+            JacobiPreconditioner::new(&a).err(),
+            Some(LinearError::SingularMatrix { pivot: 0 })
+        );
+        assert_eq!(
+            IluPreconditioner::new(&a).err(),
+            Some(LinearError::SingularMatrix { pivot: 0 })
+        );
+    }
+}
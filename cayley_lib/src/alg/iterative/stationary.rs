@@ -0,0 +1,213 @@
+use linear::{error::LinearError, matrix::matrix::Matrix, vector::vector::Vector};
+
+use super::{is_converged, norm, prepare, residual, threshold, IterativeSolution};
+
+// Stationary methods split A = D + L + U (diagonal, strictly lower and upper part)
+// and converge for strictly diagonally dominant matrices, Gauss-Seidel and SOR
+// with 0 < omega < 2 also for symmetric positive definite ones.
+
+pub struct Jacobi {}
+
+pub struct GaussSeidel {}
+
+pub struct Sor {}
+
+impl Jacobi {
+    // x_{k+1} = D^-1 * (b - (L + U) * x_k)
+    pub fn solve(
+        a: &Matrix<f64>,
+        b: &Vector<f64>,
+        x0: &Vector<f64>,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> Result<IterativeSolution, LinearError> {
+        iterate(a, b, x0, tolerance, max_iterations, |a, b, x| {
+            let previous: Vec<f64> = x.to_vec();
+            for i in 0..x.len() {
+                let mut sum: f64 = b[i];
+                for j in 0..x.len() {
+                    if j != i {
+                        sum -= a[i][j] * previous[j];
+                    }
+                }
+                x[i] = sum / a[i][i];
+            }
+        })
+    }
+}
+
+impl GaussSeidel {
+    // Jacobi that uses the already updated entries of x within a sweep, SOR with omega = 1
+    pub fn solve(
+        a: &Matrix<f64>,
+        b: &Vector<f64>,
+        x0: &Vector<f64>,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> Result<IterativeSolution, LinearError> {
+        Sor::solve(a, b, x0, 1.0, tolerance, max_iterations)
+    }
+}
+
+impl Sor {
+    // x_{k+1} = (1 - omega) * x_k + omega * x_gs where x_gs is the Gauss-Seidel update.
+    // Fails with InvalidArgument unless 0 < omega < 2, outside of that range the method
+    // diverges.
+    pub fn solve(
+        a: &Matrix<f64>,
+        b: &Vector<f64>,
+        x0: &Vector<f64>,
+        omega: f64,
+        tolerance: f64,
+        max_iterations: usize,
+    ) -> Result<IterativeSolution, LinearError> {
+        if !(omega > 0.0 && omega < 2.0) {
+            return Err(LinearError::InvalidArgument {
+                name: "omega",
+                message: "must be in (0, 2)",
+            });
+        }
+
+        iterate(a, b, x0, tolerance, max_iterations, |a, b, x| {
+            for i in 0..x.len() {
+                let mut sum: f64 = b[i];
+                for j in 0..x.len() {
+                    if j != i {
+                        sum -= a[i][j] * x[j];
+                    }
+                }
+                x[i] = (1.0 - omega) * x[i] + omega * sum / a[i][i];
+            }
+        })
+    }
+}
+
+// Runs sweep until the residual drops below the threshold or max_iterations is reached,
+// in the latter case the report says the solution has not converged.
+fn iterate<F>(
+    a: &Matrix<f64>,
+    b: &Vector<f64>,
+    x0: &Vector<f64>,
+    tolerance: f64,
+    max_iterations: usize,
+    mut sweep: F,
+) -> Result<IterativeSolution, LinearError>
+where
+    F: FnMut(&[Vec<f64>], &[f64], &mut [f64]),
+{
+    let (a, b, mut x) = prepare(a, b, x0)?;
+    if let Some(pivot) = (0..a.len()).find(|&i| a[i][i] == 0.0) {
+        return Err(LinearError::SingularMatrix { pivot });
+    }
+
+    let threshold: f64 = threshold(&b, tolerance);
+    let mut residuals: Vec<f64> = vec![norm(&residual(&a, &b, &x))];
+    while !is_converged(residuals[residuals.len() - 1], threshold) {
+        if residuals.len() > max_iterations {
+            break;
+        }
+
+        sweep(&a, &b, &mut x);
+        residuals.push(norm(&residual(&a, &b, &x)));
+    }
+
+    Ok(IterativeSolution::from_residuals(x, residuals, threshold))
+}
+
+#[cfg(test)]
+mod test {
+    use linear::{
+        error::LinearError,
+        matrix::matrix::Matrix,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::{GaussSeidel, Jacobi, Sor};
+    use crate::alg::iterative::IterativeSolution;
+
+    #[test]
+    fn test_jacobi() {
+        let (a, b, x0) = get_default_system();
+        let solution: IterativeSolution = Jacobi::solve(&a, &b, &x0, 1e-12, 200).unwrap();
+
+        assert_solution(&solution, &[1.0, 2.0, -1.0, 1.0]);
+        assert_eq!(
+            solution.report().residuals().len(),
+            solution.report().iterations() + 1
+        );
+        assert!(solution.report().final_residual() <= 1e-12 * b.dot(&b).unwrap().sqrt());
+        assert!(solution.report().converged());
+    }
+
+    #[test]
+    fn test_gauss_seidel_and_sor() {
+        let (a, b, x0) = get_default_system();
+        let jacobi: IterativeSolution = Jacobi::solve(&a, &b, &x0, 1e-12, 200).unwrap();
+        let gauss_seidel: IterativeSolution = GaussSeidel::solve(&a, &b, &x0, 1e-12, 200).unwrap();
+        let sor: IterativeSolution = Sor::solve(&a, &b, &x0, 1.1, 1e-12, 200).unwrap();
+
+        assert_solution(&gauss_seidel, &[1.0, 2.0, -1.0, 1.0]);
+        assert_solution(&sor, &[1.0, 2.0, -1.0, 1.0]);
+        assert!(gauss_seidel.report().iterations() < jacobi.report().iterations());
+
+        let residuals: &[f64] = gauss_seidel.report().residuals();
+        for k in 1..residuals.len() {
+            assert!(residuals[k] < residuals[k - 1]);
+        }
+    }
+
+    #[test]
+    fn test_not_converged() {
+        let (a, b, x0) = get_default_system();
+        let solution: IterativeSolution = Jacobi::solve(&a, &b, &x0, 1e-12, 3).unwrap();
+
+        assert!(!solution.report().converged());
+        assert_eq!(solution.report().iterations(), 3);
+        assert_eq!(solution.report().residuals().len(), 4);
+        // the residual still went down, the iterate is the one after the third sweep
+        assert!(solution.report().final_residual() < solution.report().residuals()[0]);
+        assert!(solution.x().elements().iter().any(|&e| e != 0.0));
+    }
+
+    #[test]
+    fn test_zero_diagonal() {
+        let a: Matrix<f64> = Matrix::new(&vec![vec![1.0, 2.0], vec![3.0, 0.0]]);
+        let b: Vector<f64> = Vector::new(vec![1.0, 1.0], Shape::Col);
+        assert_eq!(
+            GaussSeidel::solve(&a, &b, &b, 1e-12, 10).err(),
+            Some(LinearError::SingularMatrix { pivot: 1 })
+        );
+    }
+
+    #[test]
+    fn test_sor_invalid_omega() {
+        let (a, b, x0) = get_default_system();
+        assert_eq!(
+            Sor::solve(&a, &b, &x0, 2.0, 1e-12, 10).err(),
+            Some(LinearError::InvalidArgument {
+                name: "omega",
+                message: "must be in (0, 2)"
+            })
+        );
+        assert!(Sor::solve(&a, &b, &x0, f64::NAN, 1e-12, 10).is_err());
+    }
+
+    fn assert_solution(solution: &IterativeSolution, expected: &[f64]) {
+        for (i, e) in expected.iter().enumerate() {
+            assert!((solution.x().get(i).unwrap() - e).abs() < 1e-10);
+        }
+    }
+
+    // strictly diagonally dominant system with solution (1, 2, -1, 1)
+    fn get_default_system() -> (Matrix<f64>, Vector<f64>, Vector<f64>) {
+        let a: Matrix<f64> = Matrix::new(&vec![
+            vec![10.0, -1.0, 2.0, 0.0],
+            vec![-1.0, 11.0, -1.0, 3.0],
+            vec![2.0, -1.0, 10.0, -1.0],
+            vec![0.0, 3.0, -1.0, 8.0],
+        ]);
+        let b: Vector<f64> = Vector::new(vec![6.0, 25.0, -11.0, 15.0], Shape::Col);
+        let x0: Vector<f64> = Vector::new(vec![0.0; 4], Shape::Col);
+        (a, b, x0)
+    }
+}
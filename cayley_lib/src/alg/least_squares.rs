@@ -0,0 +1,311 @@
+use linear::{
+    error::LinearError,
+    matrix::matrix::Matrix,
+    vector::{shape::Shape, vector::Vector},
+};
+
+use super::{
+    cholesky_decomposition::CholeskyDecomposition,
+    qr_decomposition::{QrDecomposition, QrMethod},
+    singular_value_decomposition::{SingularValueDecomposition, SvdMode},
+};
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum LeastSquaresMethod {
+    // Cholesky of A^T * A, fastest but squares the condition number
+    NormalEquations,
+    // Householder QR, needs A to have full column rank
+    Qr,
+    // minimum norm solution, also for rank deficient A
+    Svd,
+}
+
+// Solution x of min ||A * x - b||_2^2 + lambda * ||x||_2^2 for an m x n matrix A.
+// lambda = 0 is ordinary least squares, lambda > 0 is Tikhonov (ridge) regularization.
+pub struct LeastSquares {
+    x: Vector<f64>,
+    residual_norm: f64,
+    rank: usize,
+}
+
+impl LeastSquares {
+    pub fn new(
+        a: &Matrix<f64>,
+        b: &Vector<f64>,
+        method: LeastSquaresMethod,
+    ) -> Result<Self, LinearError> {
+        Self::ridge(a, b, method, 0.0)
+    }
+
+    // Fails with InvalidArgument for a negative lambda.
+    pub fn ridge(
+        a: &Matrix<f64>,
+        b: &Vector<f64>,
+        method: LeastSquaresMethod,
+        lambda: f64,
+    ) -> Result<Self, LinearError> {
+        if lambda < 0.0 || lambda.is_nan() {
+            return Err(LinearError::InvalidArgument {
+                name: "lambda",
+                message: "must not be negative",
+            });
+        }
+        if a.size().rows() != b.size() {
+            return Err(LinearError::DimensionMismatch {
+                left: a.size().clone(),
+                right: b.dimensions(),
+            });
+        }
+
+        let m: usize = a.size().rows();
+        let n: usize = a.size().cols();
+        let tolerance: f64 = m.max(n) as f64 * f64::EPSILON * a.norm_frobenius();
+        let (x, rank) = match method {
+            LeastSquaresMethod::NormalEquations => {
+                (Self::normal_equations(a, b, lambda)?, a.rank(tolerance))
+            }
+            LeastSquaresMethod::Qr => (Self::qr(a, b, lambda, tolerance)?, a.rank(tolerance)),
+            LeastSquaresMethod::Svd => Self::svd(a, b, lambda)?,
+        };
+
+        let ax: Vector<f64> = a.vector_product(&x)?;
+        let residual_norm: f64 = b
+            .elements()
+            .iter()
+            .zip(ax.elements())
+            .map(|(b_i, ax_i)| (b_i - ax_i) * (b_i - ax_i))
+            .sum::<f64>()
+            .sqrt();
+
+        Ok(LeastSquares {
+            x,
+            residual_norm,
+            rank,
+        })
+    }
+
+    pub fn x(&self) -> &Vector<f64> {
+        &self.x
+    }
+
+    // ||b - A * x||_2, without the regularization term
+    pub fn residual_norm(&self) -> f64 {
+        self.residual_norm
+    }
+
+    // numerical rank of A
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    // (A^T * A + lambda * I) * x = A^T * b
+    fn normal_equations(
+        a: &Matrix<f64>,
+        b: &Vector<f64>,
+        lambda: f64,
+    ) -> Result<Vector<f64>, LinearError> {
+        let mut gram: Matrix<f64> = a.transposed().product(a)?;
+        for i in 0..a.size().cols() {
+            gram[(i, i)] += lambda;
+        }
+        let rhs: Vector<f64> = a.transposed().vector_product(b)?;
+
+        CholeskyDecomposition::new(&gram)?.solve(&rhs)
+    }
+
+    // R * x = Q^T * b for the QR decomposition of [A; sqrt(lambda) * I]
+    fn qr(
+        a: &Matrix<f64>,
+        b: &Vector<f64>,
+        lambda: f64,
+        tolerance: f64,
+    ) -> Result<Vector<f64>, LinearError> {
+        let n: usize = a.size().cols();
+        let mut rows: Vec<Vec<f64>> = (0..a.size().rows())
+            .map(|r| a.get_row(r).iter().map(|e| **e).collect())
+            .collect();
+        let mut rhs: Vec<f64> = b.elements().to_vec();
+        if lambda > 0.0 {
+            for i in 0..n {
+                let mut row: Vec<f64> = vec![0.0; n];
+                row[i] = lambda.sqrt();
+                rows.push(row);
+                rhs.push(0.0);
+            }
+        }
+
+        let qr: QrDecomposition = QrDecomposition::new(&Matrix::new(&rows), QrMethod::Householder)?;
+        let r: &Matrix<f64> = qr.r();
+        if r.size().rows() < n {
+            return Err(LinearError::LinearlyDependent {
+                column: r.size().rows(),
+            });
+        }
+
+        let qtb: Vector<f64> = qr
+            .q()
+            .transposed()
+            .vector_product(&Vector::new(rhs, Shape::Col))?;
+        let mut x: Vec<f64> = vec![0.0; n];
+        for i in (0..n).rev() {
+            let diagonal: f64 = r[(i, i)];
+            if diagonal.abs() <= tolerance {
+                return Err(LinearError::LinearlyDependent { column: i });
+            }
+
+            let mut val: f64 = qtb[i];
+            for j in (i + 1)..n {
+                val -= r[(i, j)] * x[j];
+            }
+            x[i] = val / diagonal;
+        }
+
+        Ok(Vector::new(x, Shape::Col))
+    }
+
+    // x = V * diag(sigma / (sigma^2 + lambda)) * U^T * b, singular values below the
+    // SVD tolerance are dropped which gives the minimum norm solution
+    fn svd(
+        a: &Matrix<f64>,
+        b: &Vector<f64>,
+        lambda: f64,
+    ) -> Result<(Vector<f64>, usize), LinearError> {
+        let svd: SingularValueDecomposition = SingularValueDecomposition::new(a, SvdMode::Thin)?;
+        let tolerance: f64 = svd.tolerance();
+        let utb: Vector<f64> = svd.u().transposed().vector_product(b)?;
+
+        let mut x: Vec<f64> = vec![0.0; a.size().cols()];
+        for (k, &s) in svd.sigma().elements().iter().enumerate() {
+            if s <= tolerance {
+                continue;
+            }
+            let coefficient: f64 = s / (s * s + lambda) * utb[k];
+            for (i, x_i) in x.iter_mut().enumerate() {
+                *x_i += coefficient * svd.v_t()[(k, i)];
+            }
+        }
+
+        Ok((Vector::new(x, Shape::Col), svd.rank(tolerance)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use linear::{
+        error::LinearError,
+        matrix::matrix::Matrix,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::{LeastSquares, LeastSquaresMethod};
+
+    const METHODS: [LeastSquaresMethod; 3] = [
+        LeastSquaresMethod::NormalEquations,
+        LeastSquaresMethod::Qr,
+        LeastSquaresMethod::Svd,
+    ];
+
+    #[test]
+    fn test_line_fit() {
+        // y = 1 + 2 * t sampled at t = 0..4 with noise that sums to zero
+        let (a, b) = get_default_system();
+
+        for method in METHODS {
+            let solution: LeastSquares = LeastSquares::new(&a, &b, method).unwrap();
+
+            assert!((solution.x().get(0).unwrap() - 1.04).abs() < 1e-10);
+            assert!((solution.x().get(1).unwrap() - 1.98).abs() < 1e-10);
+            assert_eq!(solution.rank(), 2);
+        }
+    }
+
+    #[test]
+    fn test_residual_is_orthogonal() {
+        let (a, b) = get_default_system();
+        let solution: LeastSquares = LeastSquares::new(&a, &b, LeastSquaresMethod::Qr).unwrap();
+        let residual: Vector<f64> = &b - &a.vector_product(solution.x()).unwrap();
+
+        let at_r: Vector<f64> = a.transposed().vector_product(&residual).unwrap();
+        for i in 0..at_r.size() {
+            assert!(at_r.get(i).unwrap().abs() < 1e-10);
+        }
+        assert!((solution.residual_norm() - residual.dot(&residual).unwrap().sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ridge() {
+        let (a, b) = get_default_system();
+        let lambda: f64 = 0.5;
+        let normal: LeastSquares =
+            LeastSquares::ridge(&a, &b, LeastSquaresMethod::NormalEquations, lambda).unwrap();
+        let plain: LeastSquares = LeastSquares::new(&a, &b, LeastSquaresMethod::Svd).unwrap();
+
+        for method in [LeastSquaresMethod::Qr, LeastSquaresMethod::Svd] {
+            let solution: LeastSquares = LeastSquares::ridge(&a, &b, method, lambda).unwrap();
+            for i in 0..2 {
+                assert!((solution.x().get(i).unwrap() - normal.x().get(i).unwrap()).abs() < 1e-10);
+            }
+        }
+        // regularization shrinks the solution and can only increase the residual
+        assert!(normal.x().dot(normal.x()).unwrap() < plain.x().dot(plain.x()).unwrap());
+        assert!(normal.residual_norm() > plain.residual_norm());
+    }
+
+    #[test]
+    fn test_rank_deficient() {
+        // second column is twice the first one
+        let a: Matrix<f64> = Matrix::new(&vec![vec![1.0, 2.0], vec![1.0, 2.0], vec![1.0, 2.0]]);
+        let b: Vector<f64> = Vector::new(vec![5.0, 5.0, 5.0], Shape::Col);
+
+        let solution: LeastSquares = LeastSquares::new(&a, &b, LeastSquaresMethod::Svd).unwrap();
+        assert_eq!(solution.rank(), 1);
+        assert!((solution.x().get(0).unwrap() - 1.0).abs() < 1e-10);
+        assert!((solution.x().get(1).unwrap() - 2.0).abs() < 1e-10);
+        assert!(solution.residual_norm() < 1e-10);
+
+        assert_eq!(
+            LeastSquares::new(&a, &b, LeastSquaresMethod::Qr).err(),
+            Some(LinearError::LinearlyDependent { column: 1 })
+        );
+        assert_eq!(
+            LeastSquares::ridge(&a, &b, LeastSquaresMethod::Qr, 1e-3)
+                .unwrap()
+                .rank(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let (a, _) = get_default_system();
+        let b: Vector<f64> = Vector::new(vec![1.0, 2.0], Shape::Col);
+        assert!(matches!(
+            LeastSquares::new(&a, &b, LeastSquaresMethod::Svd).err(),
+            Some(LinearError::DimensionMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_negative_lambda() {
+        let (a, b) = get_default_system();
+        assert_eq!(
+            LeastSquares::ridge(&a, &b, LeastSquaresMethod::Qr, -1.0).err(),
+            Some(LinearError::InvalidArgument {
+                name: "lambda",
+                message: "must not be negative"
+            })
+        );
+    }
+
+    fn get_default_system() -> (Matrix<f64>, Vector<f64>) {
+        let a: Matrix<f64> = Matrix::new(&vec![
+            vec![1.0, 0.0],
+            vec![1.0, 1.0],
+            vec![1.0, 2.0],
+            vec![1.0, 3.0],
+            vec![1.0, 4.0],
+        ]);
+        let b: Vector<f64> = Vector::new(vec![1.1, 2.9, 5.0, 7.1, 8.9], Shape::Col);
+        (a, b)
+    }
+}
@@ -0,0 +1,332 @@
+use std::ops::{Add, Div, DivAssign, Mul, SubAssign};
+
+use linear::{
+    error::LinearError,
+    matrix::matrix::Matrix,
+    num::Num,
+    vector::{shape::Shape, vector::Vector},
+};
+
+// LU decomposition with partial pivoting: P * A = L * U.
+// The factors are computed once and reused for every right-hand side.
+pub struct LuDecomposition<T> {
+    l: Matrix<T>,
+    u: Matrix<T>,
+    p: Matrix<T>,
+    permutation: Vec<usize>,
+    swaps: usize,
+    // first column without a usable pivot
+    singular_pivot: Option<usize>,
+}
+
+impl<T> LuDecomposition<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Div<Output = T> + SubAssign + DivAssign + Num,
+{
+    pub fn new(a: &Matrix<T>) -> Result<Self, LinearError> {
+        let n: usize = a.size().rows();
+        if n != a.size().cols() {
+            return Err(LinearError::NotSquare {
+                size: a.size().clone(),
+            });
+        }
+
+        let mut u: Vec<Vec<T>> = (0..n)
+            .map(|r| a.get_row(r).into_iter().cloned().collect())
+            .collect();
+        let mut l: Vec<Vec<T>> = vec![vec![T::zero(); n]; n];
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps: usize = 0;
+        let mut singular_pivot: Option<usize> = None;
+
+        for k in 0..n {
+            // Find pivot for column k:
+            let mut i_max: usize = k;
+            let mut max: T = u[k][k].absolute();
+            for (i, row) in u.iter().enumerate().skip(k + 1) {
+                let abs: T = row[k].absolute();
+                if abs > max {
+                    i_max = i;
+                    max = abs;
+                }
+            }
+
+            if i_max != k {
+                u.swap(k, i_max);
+                l.swap(k, i_max);
+                permutation.swap(k, i_max);
+                swaps += 1;
+            }
+
+            // the whole column below the diagonal is zero, nothing to eliminate
+            if u[k][k] == T::zero() {
+                singular_pivot.get_or_insert(k);
+                continue;
+            }
+
+            let (upper, lower) = u.split_at_mut(k + 1);
+            let pivot_row: &[T] = &upper[k];
+            for (i, row) in lower.iter_mut().enumerate() {
+                let factor: T = row[k].clone() / pivot_row[k].clone();
+                for (e, p) in row.iter_mut().zip(pivot_row).skip(k + 1) {
+                    let product: T = factor.clone() * p.clone();
+                    *e -= product;
+                }
+                l[k + 1 + i][k] = factor;
+                row[k] = T::zero();
+            }
+        }
+
+        for (i, row) in l.iter_mut().enumerate() {
+            row[i] = T::one();
+        }
+
+        let mut p: Vec<Vec<T>> = vec![vec![T::zero(); n]; n];
+        for (i, &j) in permutation.iter().enumerate() {
+            p[i][j] = T::one();
+        }
+
+        Ok(LuDecomposition {
+            l: Matrix::new(&l),
+            u: Matrix::new(&u),
+            p: Matrix::new(&p),
+            permutation,
+            swaps,
+            singular_pivot,
+        })
+    }
+
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    pub fn p(&self) -> &Matrix<T> {
+        &self.p
+    }
+
+    // row i of P * A is row permutation[i] of A
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn is_singular(&self) -> bool {
+        self.singular_pivot.is_some()
+    }
+
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>, LinearError> {
+        let n: usize = self.permutation.len();
+        if b.size() != n {
+            return Err(LinearError::DimensionMismatch {
+                left: self.u.size().clone(),
+                right: b.dimensions(),
+            });
+        }
+        if let Some(pivot) = self.singular_pivot {
+            return Err(LinearError::SingularMatrix { pivot });
+        }
+
+        // forward substitution: L * y = P * b
+        let mut y: Vec<T> = vec![T::zero(); n];
+        for i in 0..n {
+            let mut val: T = b.get(self.permutation[i]).unwrap().clone();
+            for (j, yj) in y.iter().enumerate().take(i) {
+                val -= self.l.get(i, j).unwrap().clone() * yj.clone();
+            }
+            y[i] = val;
+        }
+
+        // back substitution: U * x = y
+        let mut x: Vec<T> = vec![T::zero(); n];
+        for i in (0..n).rev() {
+            let mut val: T = y[i].clone();
+            for (j, xj) in x.iter().enumerate().skip(i + 1) {
+                val -= self.u.get(i, j).unwrap().clone() * xj.clone();
+            }
+            val /= self.u.get(i, i).unwrap().clone();
+            x[i] = val;
+        }
+
+        Ok(Vector::new(x, Shape::Col))
+    }
+
+    pub fn determinant(&self) -> T {
+        let mut det: T = T::zero();
+        if self.swaps.is_multiple_of(2) {
+            det += T::one();
+        } else {
+            det -= T::one();
+        }
+        for i in 0..self.permutation.len() {
+            det *= self.u.get(i, i).unwrap().clone();
+        }
+        det
+    }
+
+    pub fn inverse(&self) -> Result<Matrix<T>, LinearError> {
+        let n: usize = self.permutation.len();
+        let mut inverse: Vec<Vec<T>> = vec![vec![T::zero(); n]; n];
+        for c in 0..n {
+            let mut e: Vec<T> = vec![T::zero(); n];
+            e[c] = T::one();
+            let col: Vector<T> = self.solve(&Vector::new(e, Shape::Col))?;
+            for (r, row) in inverse.iter_mut().enumerate() {
+                row[c] = col.get(r).unwrap().clone();
+            }
+        }
+
+        Ok(Matrix::new(&inverse))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use linear::{
+        error::LinearError,
+        matrix::matrix::Matrix,
+        num::Num,
+        rational::Rational,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::LuDecomposition;
+
+    #[test]
+    fn test_factors() {
+        let a: Matrix<f64> = get_default_matrix();
+        let lu: LuDecomposition<f64> = LuDecomposition::new(&a).unwrap();
+
+        let pa: Matrix<f64> = lu.p().product(&a).unwrap();
+        let product: Matrix<f64> = lu.l().product(lu.u()).unwrap();
+
+        for i in 0..3 {
+            assert_eq!(*lu.l().get(i, i).unwrap(), 1.0);
+            for j in 0..3 {
+                if j > i {
+                    assert_eq!(*lu.l().get(i, j).unwrap(), 0.0);
+                }
+                if j < i {
+                    assert_eq!(*lu.u().get(i, j).unwrap(), 0.0);
+                }
+                assert!((pa.get(i, j).unwrap() - product.get(i, j).unwrap()).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_many() {
+        let lu: LuDecomposition<f64> = LuDecomposition::new(&get_default_matrix()).unwrap();
+
+        let b: Vector<f64> = Vector::new(vec![5.0, 7.0, 8.0], Shape::Col);
+        let x: Vector<f64> = lu.solve(&b).unwrap();
+        let expected: Vector<f64> = Vector::new(vec![-15.0, 8.0, 2.0], Shape::Col);
+        for i in 0..x.size() {
+            assert!((expected.get(i).unwrap() - x.get(i).unwrap()).abs() < 1e-12);
+        }
+
+        let b: Vector<f64> = Vector::new(vec![2.0, 14.0, 9.0], Shape::Col);
+        let x: Vector<f64> = lu.solve(&b).unwrap();
+        let expected: Vector<f64> = Vector::new(vec![1.0, 1.0, 1.0], Shape::Col);
+        for i in 0..x.size() {
+            assert!((expected.get(i).unwrap() - x.get(i).unwrap()).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_determinant() {
+        let lu: LuDecomposition<f64> = LuDecomposition::new(&get_default_matrix()).unwrap();
+        assert!((lu.determinant() - -4.0).abs() < 1e-12);
+
+        let lu: LuDecomposition<i32> =
+            LuDecomposition::new(&Matrix::new(&vec![vec![0, 1], vec![1, 0]])).unwrap();
+        assert_eq!(lu.determinant(), -1);
+    }
+
+    #[test]
+    fn test_rational() {
+        // 3 x 3 Hilbert matrix, det = 1 / 2160 and the inverse has integer entries
+        let a: Matrix<Rational> = Matrix::hilbert(3);
+        let lu: LuDecomposition<Rational> = LuDecomposition::new(&a).unwrap();
+        let expected: Matrix<Rational> = Matrix::new(
+            &[[9, -36, 30], [-36, 192, -180], [30, -180, 180]]
+                .iter()
+                .map(|row| row.iter().map(|&e| Rational::from(e)).collect())
+                .collect(),
+        );
+
+        assert_eq!(lu.determinant(), Rational::new(1, 2160));
+        assert!(lu.inverse().unwrap().equals(&expected));
+
+        // det(H_n) = c_n^4 / c_2n with c_n = 1! * 2! * ... * (n - 1)!, for n = 30 the
+        // denominator has over 500 digits
+        let c = |n: i32| {
+            (1..n)
+                .scan(Rational::one(), |factorial, i| {
+                    *factorial *= Rational::from(i);
+                    Some(factorial.clone())
+                })
+                .fold(Rational::one(), |product, factorial| product * factorial)
+        };
+        let c30: Rational = c(30);
+        let a: Matrix<Rational> = Matrix::new(
+            &(1..=30)
+                .map(|i| (1..=30).map(|j| Rational::new(1, i + j - 1)).collect())
+                .collect(),
+        );
+        let lu: LuDecomposition<Rational> = LuDecomposition::new(&a).unwrap();
+        assert_eq!(
+            lu.determinant(),
+            c30.clone() * c30.clone() * c30.clone() * c30 / c(60)
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a: Matrix<f64> = get_default_matrix();
+        let inverse: Matrix<f64> = LuDecomposition::new(&a).unwrap().inverse().unwrap();
+        let identity: Matrix<f64> = a.product(&inverse).unwrap();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected: f64 = if i == j { 1.0 } else { 0.0 };
+                assert!((identity.get(i, j).unwrap() - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_singular() {
+        let a: Matrix<f64> = Matrix::new(&vec![
+            vec![1.0, 2.0, 3.0],
+            vec![2.0, 4.0, 6.0],
+            vec![1.0, 0.0, 1.0],
+        ]);
+        let lu: LuDecomposition<f64> = LuDecomposition::new(&a).unwrap();
+        let b: Vector<f64> = Vector::new(vec![1.0, 2.0, 3.0], Shape::Col);
+
+        assert!(lu.is_singular());
+        assert_eq!(lu.determinant(), 0.0);
+        assert_eq!(
+            lu.solve(&b).err(),
+            Some(LinearError::SingularMatrix { pivot: 2 })
+        );
+        assert!(lu.inverse().is_err());
+    }
+
+    #[test]
+    fn test_not_square() {
+        let a: Matrix<f64> = Matrix::new(&vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        assert!(LuDecomposition::new(&a).is_err());
+    }
+
+    fn get_default_matrix() -> Matrix<f64> {
+        Matrix::new(&vec![
+            vec![1.0, 3.0, -2.0],
+            vec![3.0, 5.0, 6.0],
+            vec![2.0, 4.0, 3.0],
+        ])
+    }
+}
@@ -1 +1,8 @@
+pub mod cholesky_decomposition;
 pub mod gaussian_elimination;
+pub mod iterative;
+pub mod least_squares;
+pub mod lu_decomposition;
+pub mod qr_decomposition;
+pub mod singular_value_decomposition;
+pub mod symmetric_eigen;
@@ -0,0 +1,243 @@
+use linear::{error::LinearError, matrix::matrix::Matrix};
+
+// columns of Q and rows of R
+type Factors = (Vec<Vec<f64>>, Vec<Vec<f64>>);
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum QrMethod {
+    Householder,
+    ModifiedGramSchmidt,
+}
+
+// Reduced QR decomposition A = Q * R of an m x n matrix.
+// With k = min(m, n), Q is m x k with orthonormal columns and R is k x n upper triangular.
+pub struct QrDecomposition {
+    q: Matrix<f64>,
+    r: Matrix<f64>,
+}
+
+impl QrDecomposition {
+    pub fn new(a: &Matrix<f64>, method: QrMethod) -> Result<Self, LinearError> {
+        let columns: Vec<Vec<f64>> = (0..a.size().cols())
+            .map(|c| {
+                (0..a.size().rows())
+                    .map(|r| *a.get(r, c).unwrap())
+                    .collect()
+            })
+            .collect();
+
+        let (q, r) = match method {
+            QrMethod::Householder => Self::householder(columns, a.size().rows()),
+            QrMethod::ModifiedGramSchmidt => Self::modified_gram_schmidt(columns, a.size().rows())?,
+        };
+
+        Ok(QrDecomposition {
+            q: Self::from_columns(&q, a.size().rows()),
+            r: Matrix::new(&r),
+        })
+    }
+
+    pub fn q(&self) -> &Matrix<f64> {
+        &self.q
+    }
+
+    pub fn r(&self) -> &Matrix<f64> {
+        &self.r
+    }
+
+    // Householder reflections, stable for rank deficient input.
+    // Takes the columns of A and returns the columns of Q and the rows of R.
+    fn householder(mut a: Vec<Vec<f64>>, m: usize) -> Factors {
+        let n: usize = a.len();
+        let k: usize = m.min(n);
+        let mut reflectors: Vec<Option<Vec<f64>>> = Vec::with_capacity(k);
+
+        for j in 0..k {
+            let norm: f64 = a[j][j..].iter().map(|x| x * x).sum::<f64>().sqrt();
+            let alpha: f64 = if a[j][j] >= 0.0 { -norm } else { norm };
+
+            let mut v: Vec<f64> = a[j][j..].to_vec();
+            v[0] -= alpha;
+            let v_norm: f64 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            if v_norm == 0.0 {
+                reflectors.push(None);
+                continue;
+            }
+            v.iter_mut().for_each(|x| *x /= v_norm);
+
+            for col in a.iter_mut().skip(j) {
+                Self::reflect(&v, &mut col[j..]);
+            }
+            reflectors.push(Some(v));
+        }
+
+        // accumulate Q = H_0 * H_1 * ... * H_(k-1) applied to the first k unit vectors
+        let mut q: Vec<Vec<f64>> = (0..k)
+            .map(|c| {
+                let mut e: Vec<f64> = vec![0.0; m];
+                e[c] = 1.0;
+                e
+            })
+            .collect();
+        for (j, v) in reflectors.iter().enumerate().rev() {
+            if let Some(v) = v {
+                for col in q.iter_mut() {
+                    Self::reflect(v, &mut col[j..]);
+                }
+            }
+        }
+
+        let r: Vec<Vec<f64>> = (0..k)
+            .map(|i| (0..n).map(|j| if j < i { 0.0 } else { a[j][i] }).collect())
+            .collect();
+
+        (q, r)
+    }
+
+    // Modified Gram-Schmidt, requires the first min(m, n) columns to be linearly independent.
+    // Takes the columns of A and returns the columns of Q and the rows of R.
+    fn modified_gram_schmidt(a: Vec<Vec<f64>>, m: usize) -> Result<Factors, LinearError> {
+        let n: usize = a.len();
+        let k: usize = m.min(n);
+        let mut q: Vec<Vec<f64>> = Vec::with_capacity(k);
+        let mut r: Vec<Vec<f64>> = vec![vec![0.0; n]; k];
+
+        for (j, mut v) in a.into_iter().enumerate() {
+            let initial_norm: f64 = Self::norm(&v);
+            for (i, q_i) in q.iter().enumerate() {
+                let r_ij: f64 = Self::dot(q_i, &v);
+                r[i][j] = r_ij;
+                v.iter_mut().zip(q_i).for_each(|(x, y)| *x -= r_ij * y);
+            }
+
+            if j < k {
+                let r_jj: f64 = Self::norm(&v);
+                if r_jj <= m as f64 * f64::EPSILON * initial_norm {
+                    return Err(LinearError::LinearlyDependent { column: j });
+                }
+                r[j][j] = r_jj;
+                v.iter_mut().for_each(|x| *x /= r_jj);
+                q.push(v);
+            }
+        }
+
+        Ok((q, r))
+    }
+
+    // x = (I - 2 * v * v^T) * x for a unit vector v
+    fn reflect(v: &[f64], x: &mut [f64]) {
+        let factor: f64 = 2.0 * Self::dot(v, x);
+        x.iter_mut().zip(v).for_each(|(a, b)| *a -= factor * b);
+    }
+
+    fn dot(left: &[f64], right: &[f64]) -> f64 {
+        left.iter().zip(right).map(|(a, b)| a * b).sum()
+    }
+
+    fn norm(x: &[f64]) -> f64 {
+        Self::dot(x, x).sqrt()
+    }
+
+    fn from_columns(columns: &[Vec<f64>], rows: usize) -> Matrix<f64> {
+        let data: Vec<Vec<f64>> = (0..rows)
+            .map(|r| columns.iter().map(|c| c[r]).collect())
+            .collect();
+        Matrix::new(&data)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use linear::{error::LinearError, matrix::matrix::Matrix};
+
+    use super::{QrDecomposition, QrMethod};
+
+    static METHODS: &[QrMethod] = &[QrMethod::Householder, QrMethod::ModifiedGramSchmidt];
+
+    #[test]
+    fn test_square() {
+        let a: Matrix<f64> = Matrix::new(&vec![
+            vec![12.0, -51.0, 4.0],
+            vec![6.0, 167.0, -68.0],
+            vec![-4.0, 24.0, -41.0],
+        ]);
+
+        for method in METHODS {
+            let qr: QrDecomposition = QrDecomposition::new(&a, *method).unwrap();
+            assert_factorization(&a, &qr);
+            assert!((qr.r().get(0, 0).unwrap().abs() - 14.0).abs() < 1e-10);
+            assert!((qr.r().get(1, 1).unwrap().abs() - 175.0).abs() < 1e-10);
+            assert!((qr.r().get(2, 2).unwrap().abs() - 35.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_tall() {
+        let a: Matrix<f64> = Matrix::new(&vec![
+            vec![1.0, 1.0],
+            vec![1.0, 2.0],
+            vec![1.0, 3.0],
+            vec![1.0, 4.0],
+        ]);
+
+        for method in METHODS {
+            let qr: QrDecomposition = QrDecomposition::new(&a, *method).unwrap();
+            assert_eq!(qr.q().size().rows(), 4);
+            assert_eq!(qr.q().size().cols(), 2);
+            assert_eq!(qr.r().size().rows(), 2);
+            assert_eq!(qr.r().size().cols(), 2);
+            assert_factorization(&a, &qr);
+        }
+    }
+
+    #[test]
+    fn test_wide() {
+        let a: Matrix<f64> = Matrix::new(&vec![vec![3.0, 1.0, 2.0], vec![4.0, 2.0, 7.0]]);
+
+        for method in METHODS {
+            let qr: QrDecomposition = QrDecomposition::new(&a, *method).unwrap();
+            assert_eq!(qr.q().size().cols(), 2);
+            assert_eq!(qr.r().size().rows(), 2);
+            assert_eq!(qr.r().size().cols(), 3);
+            assert_factorization(&a, &qr);
+        }
+    }
+
+    #[test]
+    fn test_rank_deficient() {
+        let a: Matrix<f64> = Matrix::new(&vec![
+            vec![1.0, 2.0, 1.0],
+            vec![2.0, 4.0, 0.0],
+            vec![3.0, 6.0, 1.0],
+        ]);
+
+        let qr: QrDecomposition = QrDecomposition::new(&a, QrMethod::Householder).unwrap();
+        assert_factorization(&a, &qr);
+        assert_eq!(
+            QrDecomposition::new(&a, QrMethod::ModifiedGramSchmidt).err(),
+            Some(LinearError::LinearlyDependent { column: 1 })
+        );
+    }
+
+    fn assert_factorization(a: &Matrix<f64>, qr: &QrDecomposition) {
+        let product: Matrix<f64> = qr.q().product(qr.r()).unwrap();
+        let qtq: Matrix<f64> = qr.q().transpose().product(qr.q()).unwrap();
+
+        for i in 0..a.size().rows() {
+            for j in 0..a.size().cols() {
+                assert!((a.get(i, j).unwrap() - product.get(i, j).unwrap()).abs() < 1e-10);
+            }
+        }
+        for i in 0..qtq.size().rows() {
+            for j in 0..qtq.size().cols() {
+                let expected: f64 = if i == j { 1.0 } else { 0.0 };
+                assert!((qtq.get(i, j).unwrap() - expected).abs() < 1e-10);
+            }
+        }
+        for i in 0..qr.r().size().rows() {
+            for j in 0..i.min(qr.r().size().cols()) {
+                assert_eq!(*qr.r().get(i, j).unwrap(), 0.0);
+            }
+        }
+    }
+}
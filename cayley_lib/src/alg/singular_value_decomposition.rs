@@ -0,0 +1,403 @@
+use linear::{
+    error::LinearError,
+    matrix::matrix::Matrix,
+    vector::{shape::Shape, vector::Vector},
+};
+
+const MAX_SWEEPS: usize = 100;
+
+// columns of W and V
+type Columns = (Vec<Vec<f64>>, Vec<Vec<f64>>);
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum SvdMode {
+    // U is m x m and V^T is n x n
+    Full,
+    // U is m x k and V^T is k x n with k = min(m, n)
+    Thin,
+}
+
+// Singular value decomposition A = U * diag(sigma) * V^T computed by one-sided Jacobi rotations.
+// Singular values are sorted in descending order.
+pub struct SingularValueDecomposition {
+    u: Matrix<f64>,
+    sigma: Vector<f64>,
+    v_t: Matrix<f64>,
+}
+
+impl SingularValueDecomposition {
+    pub fn new(a: &Matrix<f64>, mode: SvdMode) -> Result<Self, LinearError> {
+        let rows: usize = a.size().rows();
+        let cols: usize = a.size().cols();
+
+        // Jacobi works on a tall matrix, so a wide one is decomposed as A^T = V * S * U^T
+        let transposed: bool = rows < cols;
+        let (m, n) = if transposed {
+            (cols, rows)
+        } else {
+            (rows, cols)
+        };
+        let columns: Vec<Vec<f64>> = (0..n)
+            .map(|c| {
+                (0..m)
+                    .map(|r| match transposed {
+                        true => *a.get(c, r).unwrap(),
+                        false => *a.get(r, c).unwrap(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let (w, v) = Self::one_sided_jacobi(columns)?;
+
+        let mut order: Vec<usize> = (0..n).collect();
+        let norms: Vec<f64> = w.iter().map(|c| Self::euclidean_norm(c)).collect();
+        order.sort_by(|&i, &j| norms[j].total_cmp(&norms[i]));
+
+        let sigma: Vec<f64> = order.iter().map(|&j| norms[j]).collect();
+        let cutoff: f64 = m as f64 * f64::EPSILON * sigma.first().copied().unwrap_or(0.0);
+        let mut left: Vec<Option<Vec<f64>>> = order
+            .iter()
+            .map(|&j| match norms[j] > cutoff && norms[j] > 0.0 {
+                true => Some(w[j].iter().map(|x| x / norms[j]).collect()),
+                false => None,
+            })
+            .collect();
+        let right: Vec<Vec<f64>> = order.iter().map(|&j| v[j].clone()).collect();
+
+        // only the full tall factor has columns beyond the singular values
+        let full_left: bool = mode == SvdMode::Full || transposed;
+        if full_left {
+            left.resize(m, None);
+        }
+        let left: Vec<Vec<f64>> = Self::complete_basis(left, m);
+
+        let (u, v_t) = if transposed {
+            let v_columns: &[Vec<f64>] = match mode {
+                SvdMode::Full => &left,
+                SvdMode::Thin => &left[..n],
+            };
+            (
+                Self::from_columns(&right, n),
+                Self::from_columns(v_columns, m).transpose(),
+            )
+        } else {
+            (
+                Self::from_columns(&left, m),
+                Self::from_columns(&right, n).transpose(),
+            )
+        };
+
+        Ok(SingularValueDecomposition {
+            u,
+            sigma: Vector::new(sigma, Shape::Col),
+            v_t,
+        })
+    }
+
+    pub fn u(&self) -> &Matrix<f64> {
+        &self.u
+    }
+
+    pub fn sigma(&self) -> &Vector<f64> {
+        &self.sigma
+    }
+
+    pub fn v_t(&self) -> &Matrix<f64> {
+        &self.v_t
+    }
+
+    // default cut-off below which singular values are treated as zero: max(m, n) * eps * sigma_max
+    pub fn tolerance(&self) -> f64 {
+        let m: usize = self.u.size().rows().max(self.v_t.size().cols());
+        m as f64 * f64::EPSILON * self.norm()
+    }
+
+    pub fn rank(&self, tolerance: f64) -> usize {
+        self.sigma
+            .elements()
+            .iter()
+            .filter(|&&s| s > tolerance)
+            .count()
+    }
+
+    // spectral norm ||A||_2
+    pub fn norm(&self) -> f64 {
+        self.sigma.elements().first().copied().unwrap_or(0.0)
+    }
+
+    // sigma_max / sigma_min, infinite for numerically singular matrices
+    pub fn condition_number(&self) -> f64 {
+        match self.sigma.elements().last() {
+            Some(&min) if min > self.tolerance() => self.norm() / min,
+            _ => f64::INFINITY,
+        }
+    }
+
+    // Moore-Penrose pseudo-inverse V * diag(1 / sigma) * U^T, ignoring sigma <= tolerance
+    pub fn pseudo_inverse(&self, tolerance: f64) -> Matrix<f64> {
+        let m: usize = self.u.size().rows();
+        let n: usize = self.v_t.size().cols();
+        let mut pinv: Vec<Vec<f64>> = vec![vec![0.0; m]; n];
+
+        for (k, &s) in self.sigma.elements().iter().enumerate() {
+            if s <= tolerance {
+                continue;
+            }
+            for (i, row) in pinv.iter_mut().enumerate() {
+                let v: f64 = self.v_t.get(k, i).unwrap() / s;
+                for (j, cell) in row.iter_mut().enumerate() {
+                    *cell += v * self.u.get(j, k).unwrap();
+                }
+            }
+        }
+
+        Matrix::new(&pinv)
+    }
+
+    // Rotates pairs of columns of W until they are mutually orthogonal, W * V stays equal to A.
+    // Returns the columns of W and V.
+    fn one_sided_jacobi(mut w: Vec<Vec<f64>>) -> Result<Columns, LinearError> {
+        let n: usize = w.len();
+        let mut v: Vec<Vec<f64>> = (0..n)
+            .map(|c| {
+                let mut e: Vec<f64> = vec![0.0; n];
+                e[c] = 1.0;
+                e
+            })
+            .collect();
+
+        for _sweep in 0..MAX_SWEEPS {
+            let mut rotated: bool = false;
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let alpha: f64 = Self::dot(&w[p], &w[p]);
+                    let beta: f64 = Self::dot(&w[q], &w[q]);
+                    let gamma: f64 = Self::dot(&w[p], &w[q]);
+                    if gamma == 0.0 || gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta: f64 = (beta - alpha) / (2.0 * gamma);
+                    let t: f64 = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                    let c: f64 = 1.0 / (1.0 + t * t).sqrt();
+                    let s: f64 = c * t;
+
+                    Self::rotate(&mut w, p, q, c, s);
+                    Self::rotate(&mut v, p, q, c, s);
+                }
+            }
+
+            if !rotated {
+                return Ok((w, v));
+            }
+        }
+
+        Err(LinearError::NotConverged {
+            iterations: MAX_SWEEPS,
+        })
+    }
+
+    fn rotate(columns: &mut [Vec<f64>], p: usize, q: usize, c: f64, s: f64) {
+        for k in 0..columns[p].len() {
+            let (x, y) = (columns[p][k], columns[q][k]);
+            columns[p][k] = c * x - s * y;
+            columns[q][k] = s * x + c * y;
+        }
+    }
+
+    // Fills the missing columns with unit vectors orthogonal to all the others.
+    fn complete_basis(columns: Vec<Option<Vec<f64>>>, m: usize) -> Vec<Vec<f64>> {
+        let mut basis: Vec<Vec<f64>> = columns.iter().flatten().cloned().collect();
+        let mut completed: Vec<Vec<f64>> = Vec::with_capacity(columns.len());
+
+        for column in columns {
+            if let Some(c) = column {
+                completed.push(c);
+                continue;
+            }
+
+            // the unit vector with the largest component outside the current basis
+            let candidate: Vec<f64> = (0..m)
+                .map(|i| {
+                    let mut e: Vec<f64> = vec![0.0; m];
+                    e[i] = 1.0;
+                    for _ in 0..2 {
+                        for b in basis.iter() {
+                            let projection: f64 = Self::dot(b, &e);
+                            e.iter_mut().zip(b).for_each(|(x, y)| *x -= projection * y);
+                        }
+                    }
+                    e
+                })
+                .max_by(|x, y| Self::euclidean_norm(x).total_cmp(&Self::euclidean_norm(y)))
+                .unwrap();
+            let norm: f64 = Self::euclidean_norm(&candidate);
+            let unit: Vec<f64> = candidate.iter().map(|x| x / norm).collect();
+
+            basis.push(unit.clone());
+            completed.push(unit);
+        }
+
+        completed
+    }
+
+    fn dot(left: &[f64], right: &[f64]) -> f64 {
+        left.iter().zip(right).map(|(a, b)| a * b).sum()
+    }
+
+    fn euclidean_norm(x: &[f64]) -> f64 {
+        Self::dot(x, x).sqrt()
+    }
+
+    fn from_columns(columns: &[Vec<f64>], rows: usize) -> Matrix<f64> {
+        let data: Vec<Vec<f64>> = (0..rows)
+            .map(|r| columns.iter().map(|c| c[r]).collect())
+            .collect();
+        Matrix::new(&data)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use linear::matrix::matrix::Matrix;
+
+    use super::{SingularValueDecomposition, SvdMode};
+
+    #[test]
+    fn test_thin_tall() {
+        let a: Matrix<f64> = get_tall_matrix();
+        let svd: SingularValueDecomposition =
+            SingularValueDecomposition::new(&a, SvdMode::Thin).unwrap();
+
+        assert_eq!(svd.u().size().rows(), 4);
+        assert_eq!(svd.u().size().cols(), 2);
+        assert_eq!(svd.sigma().size(), 2);
+        assert_eq!(svd.v_t().size().rows(), 2);
+        assert_eq!(svd.v_t().size().cols(), 2);
+        assert_reconstruction(&a, &svd);
+        assert_orthonormal_columns(svd.u());
+        assert_orthonormal_columns(&svd.v_t().transpose());
+    }
+
+    #[test]
+    fn test_full_tall() {
+        let a: Matrix<f64> = get_tall_matrix();
+        let svd: SingularValueDecomposition =
+            SingularValueDecomposition::new(&a, SvdMode::Full).unwrap();
+
+        assert_eq!(svd.u().size().rows(), 4);
+        assert_eq!(svd.u().size().cols(), 4);
+        assert_reconstruction(&a, &svd);
+        assert_orthonormal_columns(svd.u());
+    }
+
+    #[test]
+    fn test_wide() {
+        let a: Matrix<f64> = get_tall_matrix().transpose();
+
+        for mode in [SvdMode::Thin, SvdMode::Full] {
+            let svd: SingularValueDecomposition =
+                SingularValueDecomposition::new(&a, mode).unwrap();
+            let k: usize = if mode == SvdMode::Full { 4 } else { 2 };
+
+            assert_eq!(svd.u().size().rows(), 2);
+            assert_eq!(svd.u().size().cols(), 2);
+            assert_eq!(svd.v_t().size().rows(), k);
+            assert_eq!(svd.v_t().size().cols(), 4);
+            assert_reconstruction(&a, &svd);
+            assert_orthonormal_columns(&svd.v_t().transpose());
+        }
+    }
+
+    #[test]
+    fn test_singular_values() {
+        let a: Matrix<f64> = Matrix::new(&vec![vec![3.0, 2.0, 2.0], vec![2.0, 3.0, -2.0]]);
+        let svd: SingularValueDecomposition =
+            SingularValueDecomposition::new(&a, SvdMode::Thin).unwrap();
+
+        assert!((svd.sigma().get(0).unwrap() - 5.0).abs() < 1e-12);
+        assert!((svd.sigma().get(1).unwrap() - 3.0).abs() < 1e-12);
+        assert!((svd.norm() - 5.0).abs() < 1e-12);
+        assert!((svd.condition_number() - 5.0 / 3.0).abs() < 1e-12);
+        assert_eq!(svd.rank(svd.tolerance()), 2);
+    }
+
+    #[test]
+    fn test_rank_deficient() {
+        let a: Matrix<f64> = Matrix::new(&vec![
+            vec![1.0, 2.0, 3.0],
+            vec![2.0, 4.0, 6.0],
+            vec![1.0, 0.0, 1.0],
+            vec![0.0, 1.0, 1.0],
+        ]);
+        let svd: SingularValueDecomposition =
+            SingularValueDecomposition::new(&a, SvdMode::Full).unwrap();
+
+        assert_eq!(svd.rank(svd.tolerance()), 2);
+        assert_eq!(svd.condition_number(), f64::INFINITY);
+        assert_reconstruction(&a, &svd);
+        assert_orthonormal_columns(svd.u());
+    }
+
+    #[test]
+    fn test_pseudo_inverse() {
+        let a: Matrix<f64> = get_tall_matrix();
+        let svd: SingularValueDecomposition =
+            SingularValueDecomposition::new(&a, SvdMode::Thin).unwrap();
+        let pinv: Matrix<f64> = svd.pseudo_inverse(svd.tolerance());
+
+        // A * A+ * A = A
+        let apa: Matrix<f64> = a.product(&pinv).unwrap().product(&a).unwrap();
+        assert_close(&a, &apa);
+
+        // A+ = (A^T * A)^-1 * A^T for full column rank, check (A^T * A) * A+ = A^T
+        let ata: Matrix<f64> = a.transpose().product(&a).unwrap();
+        assert_close(&a.transpose(), &ata.product(&pinv).unwrap());
+    }
+
+    fn get_tall_matrix() -> Matrix<f64> {
+        Matrix::new(&vec![
+            vec![1.0, 2.0],
+            vec![3.0, 4.0],
+            vec![5.0, 6.0],
+            vec![7.0, 8.0],
+        ])
+    }
+
+    fn assert_reconstruction(a: &Matrix<f64>, svd: &SingularValueDecomposition) {
+        let k: usize = svd.sigma().size();
+        let mut reconstructed: Vec<Vec<f64>> = vec![vec![0.0; a.size().cols()]; a.size().rows()];
+        for (i, row) in reconstructed.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                for s in 0..k {
+                    *cell += svd.u().get(i, s).unwrap()
+                        * svd.sigma().get(s).unwrap()
+                        * svd.v_t().get(s, j).unwrap();
+                }
+            }
+        }
+        assert_close(a, &Matrix::new(&reconstructed));
+    }
+
+    fn assert_orthonormal_columns(m: &Matrix<f64>) {
+        let mtm: Matrix<f64> = m.transpose().product(m).unwrap();
+        for i in 0..mtm.size().rows() {
+            for j in 0..mtm.size().cols() {
+                let expected: f64 = if i == j { 1.0 } else { 0.0 };
+                assert!((mtm.get(i, j).unwrap() - expected).abs() < 1e-10);
+            }
+        }
+    }
+
+    fn assert_close(expected: &Matrix<f64>, actual: &Matrix<f64>) {
+        assert_eq!(expected.size().rows(), actual.size().rows());
+        assert_eq!(expected.size().cols(), actual.size().cols());
+        for i in 0..expected.size().rows() {
+            for j in 0..expected.size().cols() {
+                assert!((expected.get(i, j).unwrap() - actual.get(i, j).unwrap()).abs() < 1e-10);
+            }
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use linear::{
+    error::LinearError,
+    matrix::matrix::Matrix,
+    vector::{shape::Shape, vector::Vector},
+};
+
+// Eigen decomposition A = V * diag(w) * V^T of a symmetric matrix by cyclic Jacobi rotations.
+// Eigenvalues are sorted in ascending order, the i-th column of V belongs to the i-th eigenvalue.
+pub struct SymmetricEigen {
+    eigenvalues: Vector<f64>,
+    eigenvectors: Matrix<f64>,
+    sweeps: usize,
+}
+
+impl SymmetricEigen {
+    // Iterates until the off-diagonal norm drops below tolerance * ||A||_F,
+    // failing after max_sweeps full sweeps over the upper triangle.
+    pub fn new(a: &Matrix<f64>, tolerance: f64, max_sweeps: usize) -> Result<Self, LinearError> {
+        let n: usize = a.size().rows();
+        if n != a.size().cols() {
+            return Err(LinearError::NotSquare {
+                size: a.size().clone(),
+            });
+        }
+
+        let mut m: Vec<Vec<f64>> = (0..n)
+            .map(|r| a.get_row(r).iter().map(|e| **e).collect())
+            .collect();
+        for (i, row) in m.iter().enumerate() {
+            for (j, &upper) in row.iter().enumerate().skip(i + 1) {
+                let lower: f64 = m[j][i];
+                if (upper - lower).abs() > 1e-12 * upper.abs().max(lower.abs()).max(1.0) {
+                    return Err(LinearError::NotSymmetric);
+                }
+            }
+        }
+
+        let mut v: Vec<Vec<f64>> = vec![vec![0.0; n]; n];
+        for (i, row) in v.iter_mut().enumerate() {
+            row[i] = 1.0;
+        }
+
+        let threshold: f64 = tolerance * Self::frobenius_norm(&m);
+        let mut sweeps: usize = 0;
+        while Self::off_diagonal_norm(&m) > threshold {
+            if sweeps == max_sweeps {
+                return Err(LinearError::NotConverged { iterations: sweeps });
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    if m[p][q] != 0.0 {
+                        Self::rotate(&mut m, &mut v, p, q);
+                    }
+                }
+            }
+            sweeps += 1;
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| m[i][i].total_cmp(&m[j][j]));
+
+        let eigenvalues: Vec<f64> = order.iter().map(|&i| m[i][i]).collect();
+        let eigenvectors: Vec<Vec<f64>> = v
+            .iter()
+            .map(|row| order.iter().map(|&i| row[i]).collect())
+            .collect();
+
+        Ok(SymmetricEigen {
+            eigenvalues: Vector::new(eigenvalues, Shape::Col),
+            eigenvectors: Matrix::new(&eigenvectors),
+            sweeps,
+        })
+    }
+
+    pub fn eigenvalues(&self) -> &Vector<f64> {
+        &self.eigenvalues
+    }
+
+    pub fn eigenvectors(&self) -> &Matrix<f64> {
+        &self.eigenvectors
+    }
+
+    pub fn sweeps(&self) -> usize {
+        self.sweeps
+    }
+
+    // M = J^T * M * J and V = V * J where J zeroes m[p][q]
+    fn rotate(m: &mut [Vec<f64>], v: &mut [Vec<f64>], p: usize, q: usize) {
+        let theta: f64 = (m[q][q] - m[p][p]) / (2.0 * m[p][q]);
+        let t: f64 = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+        let c: f64 = 1.0 / (t * t + 1.0).sqrt();
+        let s: f64 = t * c;
+
+        for row in m.iter_mut() {
+            let (kp, kq) = (row[p], row[q]);
+            row[p] = c * kp - s * kq;
+            row[q] = s * kp + c * kq;
+        }
+        // p < q, so row p sits in the first half
+        let (head, tail) = m.split_at_mut(q);
+        for (pk, qk) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+            let (a, b) = (*pk, *qk);
+            *pk = c * a - s * b;
+            *qk = s * a + c * b;
+        }
+        for row in v.iter_mut() {
+            let (kp, kq) = (row[p], row[q]);
+            row[p] = c * kp - s * kq;
+            row[q] = s * kp + c * kq;
+        }
+    }
+
+    fn frobenius_norm(m: &[Vec<f64>]) -> f64 {
+        m.iter().flatten().map(|x| x * x).sum::<f64>().sqrt()
+    }
+
+    fn off_diagonal_norm(m: &[Vec<f64>]) -> f64 {
+        let mut sum: f64 = 0.0;
+        for (i, row) in m.iter().enumerate() {
+            for (j, x) in row.iter().enumerate() {
+                if i != j {
+                    sum += x * x;
+                }
+            }
+        }
+        sum.sqrt()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use linear::{
+        error::LinearError,
+        matrix::matrix::Matrix,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::SymmetricEigen;
+
+    #[test]
+    fn test_eigenvalues() {
+        let a: Matrix<f64> = Matrix::new(&vec![
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 2.0],
+        ]);
+        let eigen: SymmetricEigen = SymmetricEigen::new(&a, 1e-14, 50).unwrap();
+        let expected: [f64; 3] = [2.0 - 2.0_f64.sqrt(), 2.0, 2.0 + 2.0_f64.sqrt()];
+
+        for (i, e) in expected.iter().enumerate() {
+            assert!((eigen.eigenvalues().get(i).unwrap() - e).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_eigenvectors() {
+        let a: Matrix<f64> = Matrix::new(&vec![
+            vec![4.0, 1.0, -2.0, 2.0],
+            vec![1.0, 2.0, 0.0, 1.0],
+            vec![-2.0, 0.0, 3.0, -2.0],
+            vec![2.0, 1.0, -2.0, -1.0],
+        ]);
+        let eigen: SymmetricEigen = SymmetricEigen::new(&a, 1e-14, 50).unwrap();
+
+        for k in 0..4 {
+            let lambda: f64 = *eigen.eigenvalues().get(k).unwrap();
+            let v: Vector<f64> = Vector::new(
+                (0..4)
+                    .map(|r| *eigen.eigenvectors().get(r, k).unwrap())
+                    .collect(),
+                Shape::Col,
+            );
+            let av: Vector<f64> = a.vector_product(&v).unwrap();
+
+            assert!((v.dot(&v).unwrap() - 1.0).abs() < 1e-12);
+            for r in 0..4 {
+                assert!((av.get(r).unwrap() - lambda * v.get(r).unwrap()).abs() < 1e-10);
+            }
+        }
+
+        for k in 1..4 {
+            assert!(eigen.eigenvalues().get(k - 1).unwrap() <= eigen.eigenvalues().get(k).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_not_converged() {
+        let a: Matrix<f64> = Matrix::new(&vec![vec![2.0, 1.0], vec![1.0, 2.0]]);
+        assert_eq!(
+            SymmetricEigen::new(&a, 1e-14, 0).err(),
+            Some(LinearError::NotConverged { iterations: 0 })
+        );
+    }
+
+    #[test]
+    fn test_not_symmetric() {
+        let a: Matrix<f64> = Matrix::new(&vec![vec![2.0, 1.0], vec![0.0, 2.0]]);
+        assert_eq!(
+            SymmetricEigen::new(&a, 1e-14, 50).err(),
+            Some(LinearError::NotSymmetric)
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use linear::matrix::{matrix::Matrix, size::Size};
+
+// The element-by-element triple loop Matrix::product used before the tiled kernel,
+// kept here as the baseline the speedup is measured against.
+fn naive_product(left: &Matrix<f64>, right: &Matrix<f64>) -> Matrix<f64> {
+    let size: Size = Size::new(left.size().rows(), right.size().cols());
+    let mut rows: Vec<Vec<f64>> = vec![vec![0.0; size.cols()]; size.rows()];
+
+    for (row, cells) in rows.iter_mut().enumerate() {
+        for (col, cell_out) in cells.iter_mut().enumerate() {
+            let mut cell: f64 = 0.0;
+            for i in 0..right.size().rows() {
+                cell += *left.get(row, i).unwrap() * *right.get(i, col).unwrap();
+            }
+            *cell_out = cell;
+        }
+    }
+
+    Matrix::new(&rows)
+}
+
+fn bench_product(c: &mut Criterion) {
+    let mut group = c.benchmark_group("product");
+    group.sample_size(10);
+
+    for n in [64, 256, 512, 1000] {
+        let left: Matrix<f64> = Matrix::random(-1.0, 1.0, n, n);
+        let right: Matrix<f64> = Matrix::random(-1.0, 1.0, n, n);
+
+        group.bench_with_input(BenchmarkId::new("naive", n), &n, |b, _| {
+            b.iter(|| naive_product(black_box(&left), black_box(&right)))
+        });
+        group.bench_with_input(BenchmarkId::new("tiled", n), &n, |b, _| {
+            b.iter(|| black_box(&left).product(black_box(&right)).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_product);
+criterion_main!(benches);
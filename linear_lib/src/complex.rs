@@ -0,0 +1,291 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use rand::{
+    distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler},
+    Rng,
+};
+
+use crate::num::{Float, Num};
+
+// Complex number re + im * i over a floating point type.
+// Only numbers on the real axis are ordered, which is enough for pivoting on
+// absolute(), the modulus, and keeps comparisons of arbitrary values undefined.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T: Float> Complex<T> {
+    pub fn new(re: T, im: T) -> Self {
+        Complex { re, im }
+    }
+
+    pub fn i() -> Self {
+        Complex::new(T::zero(), T::one())
+    }
+
+    pub fn from_polar(modulus: T, argument: T) -> Self {
+        Complex::new(modulus * argument.cos(), modulus * argument.sin())
+    }
+
+    pub fn conj(&self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+
+    pub fn norm(&self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    // |z|^2 without the square root
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl<T: Float> Num for Complex<T> {
+    fn zero() -> Self {
+        Complex::new(T::zero(), T::zero())
+    }
+
+    fn one() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+
+    fn from_usize(u: usize) -> Self {
+        Complex::new(T::from_usize(u), T::zero())
+    }
+
+    fn absolute(&self) -> Self {
+        Complex::new(self.norm(), T::zero())
+    }
+
+    fn lowest() -> Self {
+        Complex::new(T::lowest(), T::zero())
+    }
+
+    fn highest() -> Self {
+        Complex::new(T::highest(), T::zero())
+    }
+
+    fn conjugate(&self) -> Self {
+        self.conj()
+    }
+}
+
+impl<T: Float> PartialOrd for Complex<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+        if self.im == T::zero() && other.im == T::zero() {
+            return self.re.partial_cmp(&other.re);
+        }
+        None
+    }
+}
+
+// Honors width and precision of the formatter, e.g. {:>12.2} gives "  1.00-2.50i".
+impl<T: Float> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign: char = if self.im < T::zero() { '-' } else { '+' };
+        let im: T = self.im.absolute();
+        let formatted: String = match f.precision() {
+            Some(p) => format!("{:.p$}{sign}{:.p$}i", self.re, im),
+            None => format!("{}{sign}{}i", self.re, im),
+        };
+        f.pad_integral(true, "", &formatted)
+    }
+}
+
+impl<T: Float> Add for Complex<T> {
+    type Output = Complex<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: Float> Sub for Complex<T> {
+    type Output = Complex<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: Float> Mul for Complex<T> {
+    type Output = Complex<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<T: Float> Div for Complex<T> {
+    type Output = Complex<T>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        let denominator: T = rhs.norm_sqr();
+        Complex::new(
+            (self.re * rhs.re + self.im * rhs.im) / denominator,
+            (self.im * rhs.re - self.re * rhs.im) / denominator,
+        )
+    }
+}
+
+impl<T: Float> Neg for Complex<T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Self::Output {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<T: Float> AddAssign for Complex<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Float> SubAssign for Complex<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Float> MulAssign for Complex<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Float> DivAssign for Complex<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+// Samples the real and imaginary part independently, so random(low, high) fills the
+// rectangle spanned by low and high. A part with equal bounds is kept constant.
+pub struct UniformComplex<T: SampleUniform> {
+    re: Part<T>,
+    im: Part<T>,
+}
+
+enum Part<T: SampleUniform> {
+    Constant(T),
+    Uniform(T::Sampler),
+}
+
+impl<T: Float> Part<T> {
+    fn new(low: T, high: T, inclusive: bool) -> Self {
+        if low == high {
+            Part::Constant(low)
+        } else if inclusive {
+            Part::Uniform(T::Sampler::new_inclusive(low, high))
+        } else {
+            Part::Uniform(T::Sampler::new(low, high))
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+        match self {
+            Part::Constant(val) => *val,
+            Part::Uniform(sampler) => sampler.sample(rng),
+        }
+    }
+}
+
+impl<T: Float> UniformSampler for UniformComplex<T> {
+    type X = Complex<T>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (*low.borrow(), *high.borrow());
+        UniformComplex {
+            re: Part::new(low.re, high.re, false),
+            im: Part::new(low.im, high.im, false),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (*low.borrow(), *high.borrow());
+        UniformComplex {
+            re: Part::new(low.re, high.re, true),
+            im: Part::new(low.im, high.im, true),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Complex::new(self.re.sample(rng), self.im.sample(rng))
+    }
+}
+
+impl<T: Float> SampleUniform for Complex<T> {
+    type Sampler = UniformComplex<T>;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{matrix::matrix::Matrix, num::Num};
+
+    use super::Complex;
+
+    #[test]
+    fn test_arithmetic() {
+        let a: Complex<f64> = Complex::new(1.0, 2.0);
+        let b: Complex<f64> = Complex::new(3.0, -1.0);
+
+        assert_eq!(a + b, Complex::new(4.0, 1.0));
+        assert_eq!(a - b, Complex::new(-2.0, 3.0));
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(Complex::<f64>::i() * Complex::i(), -Complex::one());
+        assert_eq!(a.conjugate(), Complex::new(1.0, -2.0));
+        assert_eq!(Complex::new(3.0, 4.0).absolute(), Complex::new(5.0, 0.0));
+    }
+
+    #[test]
+    fn test_ordering() {
+        let a: Complex<f64> = Complex::new(1.0, 2.0);
+
+        assert!(Complex::new(5.0, 0.0) > Complex::new(3.0, 0.0));
+        assert_eq!(a.partial_cmp(&a), Some(std::cmp::Ordering::Equal));
+        assert_eq!(a.partial_cmp(&Complex::new(1.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Complex::new(1.5, -2.0).to_string(), "1.5-2i");
+        assert_eq!(format!("{:>10.1}", Complex::new(1.0, 0.25)), "  1.0+0.2i");
+    }
+
+    #[test]
+    fn test_random() {
+        let low: Complex<f64> = Complex::new(-1.0, 0.0);
+        let high: Complex<f64> = Complex::new(1.0, 2.0);
+        let matrix: Matrix<Complex<f64>> = Matrix::random(low, high, 4, 4);
+
+        for r in 0..4 {
+            for c in 0..4 {
+                let z: Complex<f64> = matrix[(r, c)];
+                assert!(z.re >= -1.0 && z.re < 1.0 && z.im >= 0.0 && z.im < 2.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::{error::Error, fmt};
+
+use crate::matrix::size::Size;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum LinearError {
+    // operands have incompatible sizes, vectors are reported as 1 x n or n x 1
+    DimensionMismatch {
+        left: Size,
+        right: Size,
+    },
+    NotSquare {
+        size: Size,
+    },
+    NotSymmetric,
+    // no usable pivot was found in the given column
+    SingularMatrix {
+        pivot: usize,
+    },
+    NotPositiveDefinite {
+        pivot: usize,
+    },
+    LinearlyDependent {
+        column: usize,
+    },
+    NotConverged {
+        iterations: usize,
+    },
+    IndexOutOfBounds {
+        row: usize,
+        col: usize,
+        size: Size,
+    },
+    // a scalar parameter is outside of the range it is defined for
+    InvalidArgument {
+        name: &'static str,
+        message: &'static str,
+    },
+}
+
+impl fmt::Display for LinearError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinearError::DimensionMismatch { left, right } => write!(
+                f,
+                "dimension mismatch: {}x{} and {}x{}",
+                left.rows(),
+                left.cols(),
+                right.rows(),
+                right.cols()
+            ),
+            LinearError::NotSquare { size } => write!(
+                f,
+                "matrix must be square, got {}x{}",
+                size.rows(),
+                size.cols()
+            ),
+            LinearError::NotSymmetric => write!(f, "matrix is not symmetric"),
+            LinearError::SingularMatrix { pivot } => {
+                write!(f, "matrix is singular, no pivot in column {pivot}")
+            }
+            LinearError::NotPositiveDefinite { pivot } => write!(
+                f,
+                "matrix is not positive definite, non-positive pivot in column {pivot}"
+            ),
+            LinearError::LinearlyDependent { column } => {
+                write!(
+                    f,
+                    "column {column} is linearly dependent on the previous ones"
+                )
+            }
+            LinearError::NotConverged { iterations } => {
+                write!(f, "did not converge after {iterations} iterations")
+            }
+            LinearError::IndexOutOfBounds { row, col, size } => write!(
+                f,
+                "index ({row}, {col}) is out of bounds for a {}x{} matrix",
+                size.rows(),
+                size.cols()
+            ),
+            LinearError::InvalidArgument { name, message } => {
+                write!(f, "invalid argument {name}: {message}")
+            }
+        }
+    }
+}
+
+impl Error for LinearError {}
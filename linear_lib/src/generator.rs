@@ -1,13 +1,218 @@
-use rand::{distributions::uniform::SampleUniform, rngs::ThreadRng, Rng};
+use rand::{
+    distributions::{uniform::SampleUniform, Uniform},
+    rngs::StdRng,
+    Rng, SeedableRng,
+};
 
+use crate::{
+    error::LinearError,
+    num::{Float, Num},
+};
+
+// Random elements from any Rng. Pass a seeded one, e.g. Generator::seeded(42), for
+// results that repeat across runs; random_elements draws from rand::thread_rng().
 pub struct Generator {}
 
 impl Generator {
+    // reproducible generator, the same seed gives the same sequence
+    pub fn seeded(seed: u64) -> StdRng {
+        StdRng::seed_from_u64(seed)
+    }
+
     pub fn random_elements<T>(low: T, high: T, size: usize) -> Vec<T>
     where
-        T: Copy + PartialOrd + SampleUniform,
+        T: Clone + PartialOrd + SampleUniform,
+    {
+        Self::uniform(&mut rand::thread_rng(), low, high, size)
+    }
+
+    // goes through Uniform instead of gen_range, which rejects ranges whose bounds
+    // are not ordered (complex numbers are sampled per component)
+    pub fn uniform<T, R>(rng: &mut R, low: T, high: T, size: usize) -> Vec<T>
+    where
+        T: Clone + PartialOrd + SampleUniform,
+        R: Rng + ?Sized,
     {
-        let mut rng: ThreadRng = rand::thread_rng();
-        (0..size).map(|_| rng.gen_range(low..high)).collect()
+        let distribution: Uniform<T> = Uniform::new(low, high);
+        (0..size).map(|_| rng.sample(&distribution)).collect()
+    }
+
+    // Box-Muller transform, computed in f64
+    pub fn standard_normal<T: Float, R: Rng + ?Sized>(rng: &mut R, size: usize) -> Vec<T> {
+        let mut elements: Vec<T> = Vec::with_capacity(size + 1);
+        while elements.len() < size {
+            // 1 - [0, 1) keeps the logarithm finite
+            let u1: f64 = 1.0 - rng.gen::<f64>();
+            let u2: f64 = rng.gen::<f64>();
+            let radius: f64 = (-2.0 * u1.ln()).sqrt();
+            let angle: f64 = 2.0 * std::f64::consts::PI * u2;
+            elements.push(T::from_f64(radius * angle.cos()));
+            elements.push(T::from_f64(radius * angle.sin()));
+        }
+        elements.truncate(size);
+        elements
+    }
+
+    pub fn normal<T: Float, R: Rng + ?Sized>(
+        rng: &mut R,
+        mean: T,
+        std_dev: T,
+        size: usize,
+    ) -> Result<Vec<T>, LinearError> {
+        if std_dev < T::zero() || std_dev.is_nan() {
+            return Err(LinearError::InvalidArgument {
+                name: "std_dev",
+                message: "must not be negative",
+            });
+        }
+        Ok(Self::standard_normal(rng, size)
+            .into_iter()
+            .map(|z: T| mean + std_dev * z)
+            .collect())
+    }
+
+    // one with probability p, zero otherwise
+    pub fn bernoulli<T: Num, R: Rng + ?Sized>(
+        rng: &mut R,
+        p: f64,
+        size: usize,
+    ) -> Result<Vec<T>, LinearError> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(LinearError::InvalidArgument {
+                name: "p",
+                message: "must be in [0, 1]",
+            });
+        }
+        Ok((0..size)
+            .map(|_| if rng.gen_bool(p) { T::one() } else { T::zero() })
+            .collect())
+    }
+
+    // Glorot uniform: U(-a, a) with a = sqrt(6 / (fan_in + fan_out)), keeps the
+    // variance of activations and gradients about equal for tanh and sigmoid layers
+    pub fn xavier<T: Float, R: Rng + ?Sized>(
+        rng: &mut R,
+        fan_in: usize,
+        fan_out: usize,
+        size: usize,
+    ) -> Result<Vec<T>, LinearError> {
+        if fan_in + fan_out == 0 {
+            return Err(LinearError::InvalidArgument {
+                name: "fan_in + fan_out",
+                message: "must be positive",
+            });
+        }
+        let bound: T = T::from_f64((6.0 / (fan_in + fan_out) as f64).sqrt());
+        Ok(Self::uniform(rng, -bound, bound, size))
+    }
+
+    // He normal: N(0, 2 / fan_in), the counterpart of xavier for ReLU layers
+    pub fn he<T: Float, R: Rng + ?Sized>(
+        rng: &mut R,
+        fan_in: usize,
+        size: usize,
+    ) -> Result<Vec<T>, LinearError> {
+        if fan_in == 0 {
+            return Err(LinearError::InvalidArgument {
+                name: "fan_in",
+                message: "must be positive",
+            });
+        }
+        let std_dev: T = T::from_f64((2.0 / fan_in as f64).sqrt());
+        Self::normal(rng, T::zero(), std_dev, size)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+
+    use crate::error::LinearError;
+
+    use super::Generator;
+
+    fn mean_and_variance(elements: &[f64]) -> (f64, f64) {
+        let n: f64 = elements.len() as f64;
+        let mean: f64 = elements.iter().sum::<f64>() / n;
+        let variance: f64 = elements.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        (mean, variance)
+    }
+
+    #[test]
+    fn test_seeded() {
+        let first: Vec<i64> = Generator::uniform(&mut Generator::seeded(7), -100, 100, 50);
+        let second: Vec<i64> = Generator::uniform(&mut Generator::seeded(7), -100, 100, 50);
+        let other: Vec<i64> = Generator::uniform(&mut Generator::seeded(8), -100, 100, 50);
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn test_normal() {
+        let mut rng: StdRng = Generator::seeded(1);
+        let elements: Vec<f64> = Generator::normal(&mut rng, 3.0, 2.0, 100001).unwrap();
+        let (mean, variance) = mean_and_variance(&elements);
+
+        assert_eq!(elements.len(), 100001);
+        assert!((mean - 3.0).abs() < 0.05);
+        assert!((variance - 4.0).abs() < 0.1);
+
+        let standard: Vec<f32> = Generator::standard_normal(&mut rng, 3);
+        assert_eq!(standard.len(), 3);
+    }
+
+    #[test]
+    fn test_bernoulli() {
+        let mut rng: StdRng = Generator::seeded(2);
+        let elements: Vec<u8> = Generator::bernoulli(&mut rng, 0.25, 10000).unwrap();
+        let ones: usize = elements.iter().filter(|&&x| x == 1).count();
+
+        assert!(elements.iter().all(|&x| x <= 1));
+        assert!((ones as f64 / 10000.0 - 0.25).abs() < 0.02);
+        assert!(Generator::bernoulli::<i32, _>(&mut rng, 0.0, 100)
+            .unwrap()
+            .iter()
+            .all(|&x| x == 0));
+    }
+
+    #[test]
+    fn test_initializers() {
+        let mut rng: StdRng = Generator::seeded(3);
+
+        let xavier: Vec<f64> = Generator::xavier(&mut rng, 100, 50, 20000).unwrap();
+        let bound: f64 = (6.0_f64 / 150.0).sqrt();
+        let (_, variance) = mean_and_variance(&xavier);
+        assert!(xavier.iter().all(|x| x.abs() < bound));
+        assert!((variance - 2.0 / 150.0).abs() < 0.001);
+
+        let he: Vec<f64> = Generator::he(&mut rng, 100, 20000).unwrap();
+        let (mean, variance) = mean_and_variance(&he);
+        assert!(mean.abs() < 0.01);
+        assert!((variance - 0.02).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        let mut rng: StdRng = Generator::seeded(4);
+
+        assert_eq!(
+            Generator::normal(&mut rng, 0.0, -1.0, 10).err(),
+            Some(LinearError::InvalidArgument {
+                name: "std_dev",
+                message: "must not be negative"
+            })
+        );
+        assert!(Generator::normal(&mut rng, 0.0, f64::NAN, 10).is_err());
+        assert!(Generator::bernoulli::<u8, _>(&mut rng, 1.5, 10).is_err());
+        assert!(Generator::bernoulli::<u8, _>(&mut rng, f64::NAN, 10).is_err());
+        assert!(Generator::xavier::<f64, _>(&mut rng, 0, 0, 10).is_err());
+        assert_eq!(
+            Generator::he::<f64, _>(&mut rng, 0, 10).err(),
+            Some(LinearError::InvalidArgument {
+                name: "fan_in",
+                message: "must be positive"
+            })
+        );
     }
 }
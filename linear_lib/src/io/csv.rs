@@ -0,0 +1,206 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    ops::{Add, Mul},
+    str::FromStr,
+};
+
+use crate::{matrix::matrix::Matrix, num::Num};
+
+use super::error::IoError;
+
+// Delimited text, one matrix row per line. Blank lines are skipped and fields are
+// trimmed, so "1, 2" and "1,2" read the same.
+pub struct Csv {
+    delimiter: char,
+    has_header: bool,
+}
+
+impl Csv {
+    // has_header makes the reader take the first line as column names
+    pub fn new(delimiter: char, has_header: bool) -> Self {
+        Csv {
+            delimiter,
+            has_header,
+        }
+    }
+
+    pub fn comma(has_header: bool) -> Self {
+        Csv::new(',', has_header)
+    }
+
+    pub fn tab(has_header: bool) -> Self {
+        Csv::new('\t', has_header)
+    }
+
+    pub fn read<T, R>(&self, reader: R) -> Result<Matrix<T>, IoError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Num + FromStr,
+        R: Read,
+    {
+        Ok(self.read_with_header(reader)?.1)
+    }
+
+    // column names are None without has_header, quotes around them are removed
+    pub fn read_with_header<T, R>(
+        &self,
+        reader: R,
+    ) -> Result<(Option<Vec<String>>, Matrix<T>), IoError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Num + FromStr,
+        R: Read,
+    {
+        let mut header: Option<Vec<String>> = None;
+        let mut rows: Vec<Vec<T>> = vec![];
+        for (index, line) in BufReader::new(reader).lines().enumerate() {
+            let line: String = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split(self.delimiter).map(|f| f.trim()).collect();
+            if self.has_header && header.is_none() {
+                header = Some(
+                    fields
+                        .iter()
+                        .map(|f| f.trim_matches('"').to_string())
+                        .collect(),
+                );
+                continue;
+            }
+
+            let expected: usize = match (&header, rows.first()) {
+                (Some(names), _) => names.len(),
+                (None, Some(first)) => first.len(),
+                (None, None) => fields.len(),
+            };
+            if fields.len() != expected {
+                return Err(IoError::RaggedRow {
+                    line: index + 1,
+                    expected,
+                    found: fields.len(),
+                });
+            }
+
+            let row: Vec<T> = fields
+                .iter()
+                .map(|f| {
+                    f.parse::<T>().map_err(|_| IoError::InvalidNumber {
+                        line: index + 1,
+                        value: f.to_string(),
+                    })
+                })
+                .collect::<Result<Vec<T>, IoError>>()?;
+            rows.push(row);
+        }
+
+        if rows.is_empty() {
+            return Err(IoError::Empty);
+        }
+        Ok((header, Matrix::new(&rows)))
+    }
+
+    // A header without one name per column is reported as a ragged first line.
+    pub fn write<T, W>(
+        &self,
+        matrix: &Matrix<T>,
+        header: Option<&[&str]>,
+        mut writer: W,
+    ) -> Result<(), IoError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Num,
+        W: Write,
+    {
+        let delimiter: String = self.delimiter.to_string();
+        if let Some(names) = header {
+            if names.len() != matrix.size().cols() {
+                return Err(IoError::RaggedRow {
+                    line: 1,
+                    expected: matrix.size().cols(),
+                    found: names.len(),
+                });
+            }
+            writeln!(writer, "{}", names.join(&delimiter))?;
+        }
+
+        for r in 0..matrix.size().rows() {
+            let row: Vec<String> = matrix.get_row(r).iter().map(|e| e.to_string()).collect();
+            writeln!(writer, "{}", row.join(&delimiter))?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{io::error::IoError, matrix::matrix::Matrix};
+
+    use super::Csv;
+
+    #[test]
+    fn test_read() {
+        let text: &str = "x, y, z\n1.5, 2, -3\n\n4, 5e-1, 6\n";
+        let (header, matrix) = Csv::comma(true)
+            .read_with_header::<f64, _>(text.as_bytes())
+            .unwrap();
+
+        assert_eq!(header.unwrap(), vec!["x", "y", "z"]);
+        assert!(matrix.equals(&Matrix::new(&vec![
+            vec![1.5, 2.0, -3.0],
+            vec![4.0, 0.5, 6.0]
+        ])));
+
+        let matrix: Matrix<i32> = Csv::tab(false).read("1\t2\n3\t4".as_bytes()).unwrap();
+        assert!(matrix.equals(&Matrix::new(&vec![vec![1, 2], vec![3, 4]])));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let matrix: Matrix<f64> = Matrix::new(&vec![vec![0.1, -2.0], vec![1e-12, 3.25]]);
+        let csv: Csv = Csv::new(';', true);
+        let mut buffer: Vec<u8> = vec![];
+        csv.write(&matrix, Some(&["a", "b"]), &mut buffer).unwrap();
+
+        assert_eq!(
+            String::from_utf8(buffer.clone()).unwrap(),
+            "a;b\n0.1;-2\n0.000000000001;3.25\n"
+        );
+        assert!(csv
+            .read::<f64, _>(buffer.as_slice())
+            .unwrap()
+            .equals(&matrix));
+    }
+
+    #[test]
+    fn test_errors() {
+        let csv: Csv = Csv::comma(false);
+
+        assert!(matches!(
+            csv.read::<i32, _>("1,2\n3,x\n".as_bytes()),
+            Err(IoError::InvalidNumber { line: 2, value }) if value == "x"
+        ));
+        assert!(matches!(
+            csv.read::<i32, _>("1,2\n\n3,4,5\n".as_bytes()),
+            Err(IoError::RaggedRow {
+                line: 3,
+                expected: 2,
+                found: 3
+            })
+        ));
+        assert!(matches!(
+            Csv::comma(true).read::<i32, _>("a,b\n".as_bytes()),
+            Err(IoError::Empty)
+        ));
+
+        let mut buffer: Vec<u8> = vec![];
+        assert!(matches!(
+            csv.write(&Matrix::new(&vec![vec![1, 2]]), Some(&["a"]), &mut buffer),
+            Err(IoError::RaggedRow {
+                line: 1,
+                expected: 2,
+                found: 1
+            })
+        ));
+        assert!(buffer.is_empty());
+    }
+}
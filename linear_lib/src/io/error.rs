@@ -0,0 +1,130 @@
+use std::{error::Error, fmt, io};
+
+use zip::result::ZipError;
+
+use crate::matrix::size::Size;
+
+// Failure to read or write a matrix file. Line numbers are 1-based.
+#[derive(Debug)]
+pub enum IoError {
+    Io(io::Error),
+    // a field that does not parse as the requested element type or index
+    InvalidNumber {
+        line: usize,
+        value: String,
+    },
+    // a line with a different number of fields than expected
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    // malformed Matrix Market banner or size line
+    InvalidHeader {
+        line: usize,
+        message: String,
+    },
+    // valid Matrix Market file using a field or symmetry that is not supported
+    Unsupported {
+        line: usize,
+        message: String,
+    },
+    // 1-based Matrix Market indices outside of the declared size
+    IndexOutOfBounds {
+        line: usize,
+        row: usize,
+        col: usize,
+        size: Size,
+    },
+    // more or fewer entries than the size line declares
+    EntryCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    // no data at all
+    Empty,
+    // malformed binary file, e.g. a bad .npy magic string or header
+    InvalidFormat {
+        message: String,
+    },
+    // stored element type differs from the requested one, as numpy type codes
+    DtypeMismatch {
+        expected: String,
+        found: String,
+    },
+    // array name not present in an .npz archive
+    MissingArray {
+        name: String,
+    },
+}
+
+impl fmt::Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IoError::Io(err) => write!(f, "{err}"),
+            IoError::InvalidNumber { line, value } => {
+                write!(f, "line {line}: invalid number '{value}'")
+            }
+            IoError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(f, "line {line}: expected {expected} fields, found {found}"),
+            IoError::InvalidHeader { line, message } => {
+                write!(f, "line {line}: invalid header, {message}")
+            }
+            IoError::Unsupported { line, message } => {
+                write!(f, "line {line}: unsupported {message}")
+            }
+            IoError::IndexOutOfBounds {
+                line,
+                row,
+                col,
+                size,
+            } => write!(
+                f,
+                "line {line}: index ({row}, {col}) is out of bounds for a {}x{} matrix",
+                size.rows(),
+                size.cols()
+            ),
+            IoError::EntryCount {
+                line,
+                expected,
+                found,
+            } => write!(f, "line {line}: expected {expected} entries, found {found}"),
+            IoError::Empty => write!(f, "no data"),
+            IoError::InvalidFormat { message } => write!(f, "invalid format, {message}"),
+            IoError::DtypeMismatch { expected, found } => {
+                write!(f, "expected dtype {expected}, found {found}")
+            }
+            IoError::MissingArray { name } => write!(f, "no array named '{name}'"),
+        }
+    }
+}
+
+impl Error for IoError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            IoError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for IoError {
+    fn from(err: io::Error) -> Self {
+        IoError::Io(err)
+    }
+}
+
+impl From<ZipError> for IoError {
+    fn from(err: ZipError) -> Self {
+        match err {
+            ZipError::Io(err) => IoError::Io(err),
+            err => IoError::InvalidFormat {
+                message: err.to_string(),
+            },
+        }
+    }
+}
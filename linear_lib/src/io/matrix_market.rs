@@ -0,0 +1,492 @@
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    ops::{Add, Mul, Sub},
+    str::FromStr,
+};
+
+use crate::{
+    matrix::{matrix::Matrix, size::Size},
+    num::Num,
+    sparse::{coo::CooMatrix, csr::CsrMatrix},
+};
+
+use super::error::IoError;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum MatrixMarketFormat {
+    // "i j value" lines for the non-zero elements, 1-based
+    Coordinate,
+    // every element, one per line in column-major order
+    Array,
+}
+
+// Reader and writer for the NIST Matrix Market exchange format (.mtx).
+// Real, integer and pattern fields with general, symmetric and skew-symmetric
+// storage are supported, complex and hermitian files are rejected.
+pub struct MatrixMarket {}
+
+#[derive(PartialEq, Clone, Copy)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+struct Header {
+    format: MatrixMarketFormat,
+    pattern: bool,
+    symmetry: Symmetry,
+}
+
+impl MatrixMarket {
+    pub fn read<T, R>(reader: R) -> Result<Matrix<T>, IoError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num + FromStr,
+        R: Read,
+    {
+        Ok(Self::read_sparse(reader)?.to_matrix())
+    }
+
+    // Symmetric storage is expanded, so the result holds both triangles.
+    pub fn read_sparse<T, R>(reader: R) -> Result<CooMatrix<T>, IoError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num + FromStr,
+        R: Read,
+    {
+        let mut lines = BufReader::new(reader)
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line));
+
+        let header: Header = match lines.next() {
+            Some((number, line)) => Self::parse_banner(number, &line?)?,
+            None => return Err(IoError::Empty),
+        };
+
+        let (number, line) = Self::next_line(&mut lines)?.ok_or(IoError::Empty)?;
+        let expected_fields: usize = match header.format {
+            MatrixMarketFormat::Coordinate => 3,
+            MatrixMarketFormat::Array => 2,
+        };
+        let dimensions: Vec<usize> = Self::parse_fields(number, &line, expected_fields)?;
+        let (rows, cols) = (dimensions[0], dimensions[1]);
+        if header.symmetry != Symmetry::General && rows != cols {
+            return Err(IoError::InvalidHeader {
+                line: number,
+                message: format!("symmetric storage of a {rows}x{cols} matrix"),
+            });
+        }
+
+        let mut coo: CooMatrix<T> = CooMatrix::new(rows, cols);
+        // the array format lists columns top to bottom, symmetric matrices only from the
+        // diagonal down and skew-symmetric ones only below it
+        let first_row = |col: usize| -> usize {
+            match header.symmetry {
+                Symmetry::General => 0,
+                Symmetry::Symmetric => col,
+                Symmetry::SkewSymmetric => col + 1,
+            }
+        };
+        let entries: usize = match header.format {
+            MatrixMarketFormat::Coordinate => dimensions[2],
+            MatrixMarketFormat::Array => {
+                let stored: Option<usize> = match header.symmetry {
+                    Symmetry::General => rows.checked_mul(cols),
+                    Symmetry::Symmetric => rows
+                        .checked_add(1)
+                        .and_then(|n| n.checked_mul(rows))
+                        .map(|n| n / 2),
+                    Symmetry::SkewSymmetric => {
+                        rows.checked_mul(rows.saturating_sub(1)).map(|n| n / 2)
+                    }
+                };
+                stored.ok_or_else(|| IoError::InvalidHeader {
+                    line: number,
+                    message: format!("{rows}x{cols} entries do not fit in memory"),
+                })?
+            }
+        };
+
+        // position of the next array entry
+        let (mut array_row, mut array_col): (usize, usize) = (first_row(0), 0);
+        let mut last: usize = number;
+        for k in 0..entries {
+            let (number, line) = match Self::next_line(&mut lines)? {
+                Some(next) => next,
+                None => {
+                    return Err(IoError::EntryCount {
+                        line: last,
+                        expected: entries,
+                        found: k,
+                    })
+                }
+            };
+            last = number;
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (row, col, val) = match header.format {
+                MatrixMarketFormat::Coordinate => {
+                    let expected: usize = if header.pattern { 2 } else { 3 };
+                    if fields.len() != expected {
+                        return Err(IoError::RaggedRow {
+                            line: number,
+                            expected,
+                            found: fields.len(),
+                        });
+                    }
+                    let row: usize = Self::parse(number, fields[0])?;
+                    let col: usize = Self::parse(number, fields[1])?;
+                    if row == 0 || col == 0 || row > rows || col > cols {
+                        return Err(IoError::IndexOutOfBounds {
+                            line: number,
+                            row,
+                            col,
+                            size: Size::new(rows, cols),
+                        });
+                    }
+                    let val: T = if header.pattern {
+                        T::one()
+                    } else {
+                        Self::parse(number, fields[2])?
+                    };
+                    (row - 1, col - 1, val)
+                }
+                MatrixMarketFormat::Array => {
+                    if fields.len() != 1 {
+                        return Err(IoError::RaggedRow {
+                            line: number,
+                            expected: 1,
+                            found: fields.len(),
+                        });
+                    }
+                    let (row, col) = (array_row, array_col);
+                    array_row += 1;
+                    if array_row == rows {
+                        array_col += 1;
+                        array_row = first_row(array_col);
+                    }
+                    (row, col, Self::parse(number, fields[0])?)
+                }
+            };
+
+            if val == T::zero() {
+                continue;
+            }
+            // indices are checked above, push can not fail
+            coo.push(row, col, val.clone()).unwrap();
+            if row != col {
+                match header.symmetry {
+                    Symmetry::General => {}
+                    Symmetry::Symmetric => coo.push(col, row, val).unwrap(),
+                    Symmetry::SkewSymmetric => coo.push(col, row, T::zero() - val).unwrap(),
+                }
+            }
+        }
+
+        if let Some((number, _)) = Self::next_line(&mut lines)? {
+            let mut found: usize = entries + 1;
+            while Self::next_line(&mut lines)?.is_some() {
+                found += 1;
+            }
+            return Err(IoError::EntryCount {
+                line: number,
+                expected: entries,
+                found,
+            });
+        }
+
+        Ok(coo)
+    }
+
+    // The field is "integer" if every element prints as an integer, "real" otherwise,
+    // so files written from Matrix<f64> holding whole numbers read back as Matrix<i32>.
+    pub fn write<T, W>(
+        matrix: &Matrix<T>,
+        format: MatrixMarketFormat,
+        mut writer: W,
+    ) -> Result<(), IoError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Num,
+        W: Write,
+    {
+        match format {
+            MatrixMarketFormat::Coordinate => {
+                Self::write_sparse(&CsrMatrix::from_matrix(matrix), writer)
+            }
+            MatrixMarketFormat::Array => {
+                let values: Vec<String> = (0..matrix.size().cols())
+                    .flat_map(|c| {
+                        (0..matrix.size().rows()).map(move |r| matrix[(r, c)].to_string())
+                    })
+                    .collect();
+                writeln!(
+                    writer,
+                    "%%MatrixMarket matrix array {} general",
+                    Self::field(&values)
+                )?;
+                writeln!(writer, "{} {}", matrix.size().rows(), matrix.size().cols())?;
+                for val in values {
+                    writeln!(writer, "{val}")?;
+                }
+                writer.flush()?;
+                Ok(())
+            }
+        }
+    }
+
+    // coordinate format with the stored non-zero elements
+    pub fn write_sparse<T, W>(matrix: &CsrMatrix<T>, mut writer: W) -> Result<(), IoError>
+    where
+        T: Mul<Output = T> + Add<Output = T> + Num,
+        W: Write,
+    {
+        let values: Vec<String> = matrix.values().iter().map(|v| v.to_string()).collect();
+        writeln!(
+            writer,
+            "%%MatrixMarket matrix coordinate {} general",
+            Self::field(&values)
+        )?;
+        writeln!(
+            writer,
+            "{} {} {}",
+            matrix.size().rows(),
+            matrix.size().cols(),
+            matrix.nnz()
+        )?;
+        for ((r, c, _), val) in matrix.iter().zip(values) {
+            writeln!(writer, "{} {} {val}", r + 1, c + 1)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn parse_banner(number: usize, line: &str) -> Result<Header, IoError> {
+        let words: Vec<String> = line.split_whitespace().map(|w| w.to_lowercase()).collect();
+        if words.len() != 5 || words[0] != "%%matrixmarket" || words[1] != "matrix" {
+            return Err(IoError::InvalidHeader {
+                line: number,
+                message: "expected '%%MatrixMarket matrix <format> <field> <symmetry>'".to_string(),
+            });
+        }
+
+        let format: MatrixMarketFormat = match words[2].as_str() {
+            "coordinate" => MatrixMarketFormat::Coordinate,
+            "array" => MatrixMarketFormat::Array,
+            other => {
+                return Err(IoError::InvalidHeader {
+                    line: number,
+                    message: format!("unknown format '{other}'"),
+                })
+            }
+        };
+        let pattern: bool = match words[3].as_str() {
+            "real" | "double" | "integer" => false,
+            "pattern" if format == MatrixMarketFormat::Coordinate => true,
+            "complex" => {
+                return Err(IoError::Unsupported {
+                    line: number,
+                    message: "complex field".to_string(),
+                })
+            }
+            other => {
+                return Err(IoError::InvalidHeader {
+                    line: number,
+                    message: format!("unknown field '{other}'"),
+                })
+            }
+        };
+        let symmetry: Symmetry = match words[4].as_str() {
+            "general" => Symmetry::General,
+            "symmetric" => Symmetry::Symmetric,
+            "skew-symmetric" => Symmetry::SkewSymmetric,
+            "hermitian" => {
+                return Err(IoError::Unsupported {
+                    line: number,
+                    message: "hermitian symmetry".to_string(),
+                })
+            }
+            other => {
+                return Err(IoError::InvalidHeader {
+                    line: number,
+                    message: format!("unknown symmetry '{other}'"),
+                })
+            }
+        };
+
+        Ok(Header {
+            format,
+            pattern,
+            symmetry,
+        })
+    }
+
+    // next line that is neither blank nor a % comment
+    fn next_line<I>(lines: &mut I) -> Result<Option<(usize, String)>, IoError>
+    where
+        I: Iterator<Item = (usize, io::Result<String>)>,
+    {
+        for (number, line) in lines {
+            let line: String = line?;
+            let trimmed: &str = line.trim();
+            if !trimmed.is_empty() && !trimmed.starts_with('%') {
+                return Ok(Some((number, line)));
+            }
+        }
+        Ok(None)
+    }
+
+    fn parse_fields(number: usize, line: &str, expected: usize) -> Result<Vec<usize>, IoError> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() != expected {
+            return Err(IoError::RaggedRow {
+                line: number,
+                expected,
+                found: fields.len(),
+            });
+        }
+        fields.iter().map(|f| Self::parse(number, f)).collect()
+    }
+
+    fn parse<V: FromStr>(number: usize, field: &str) -> Result<V, IoError> {
+        field.parse::<V>().map_err(|_| IoError::InvalidNumber {
+            line: number,
+            value: field.to_string(),
+        })
+    }
+
+    fn field(values: &[String]) -> &'static str {
+        if values.iter().all(|v| v.parse::<i128>().is_ok()) {
+            "integer"
+        } else {
+            "real"
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        io::error::IoError,
+        matrix::matrix::Matrix,
+        sparse::{coo::CooMatrix, csr::CsrMatrix},
+    };
+
+    use super::{MatrixMarket, MatrixMarketFormat};
+
+    #[test]
+    fn test_read_coordinate() {
+        let text: &str = "%%MatrixMarket matrix coordinate real symmetric\n\
+                          % lower triangle only\n\
+                          3 3 4\n\
+                          1 1 2.0\n\
+                          2 1 -1.0\n\
+                          3 2 -1.0\n\
+                          3 3 2.5\n";
+        let matrix: Matrix<f64> = MatrixMarket::read(text.as_bytes()).unwrap();
+        let expected: Matrix<f64> = Matrix::new(&vec![
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 0.0, -1.0],
+            vec![0.0, -1.0, 2.5],
+        ]);
+
+        assert!(matrix.equals(&expected));
+    }
+
+    #[test]
+    fn test_read_array() {
+        let text: &str = "%%MatrixMarket matrix array integer skew-symmetric\n3 3\n1\n2\n3\n";
+        let coo: CooMatrix<i32> = MatrixMarket::read_sparse(text.as_bytes()).unwrap();
+        let expected: Matrix<i32> =
+            Matrix::new(&vec![vec![0, -1, -2], vec![1, 0, -3], vec![2, 3, 0]]);
+
+        assert_eq!(coo.nnz(), 6);
+        assert!(coo.to_matrix().equals(&expected));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let matrix: Matrix<f64> =
+            Matrix::new(&vec![vec![1.5, 0.0], vec![0.0, -2.0], vec![3.0, 0.0]]);
+
+        for format in [MatrixMarketFormat::Coordinate, MatrixMarketFormat::Array] {
+            let mut buffer: Vec<u8> = vec![];
+            MatrixMarket::write(&matrix, format, &mut buffer).unwrap();
+            let read: Matrix<f64> = MatrixMarket::read(buffer.as_slice()).unwrap();
+            assert!(read.equals(&matrix));
+        }
+
+        let mut buffer: Vec<u8> = vec![];
+        MatrixMarket::write_sparse(&CsrMatrix::from_matrix(&matrix.scalar(2.0)), &mut buffer)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            "%%MatrixMarket matrix coordinate integer general\n3 2 3\n1 1 3\n2 2 -4\n3 1 6\n"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let read = |text: &str| MatrixMarket::read::<f64, _>(text.as_bytes()).err().unwrap();
+
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate complex general\n"),
+            IoError::Unsupported { line: 1, .. }
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate real general\n2 2 1\n\n3 1 1.0\n"),
+            IoError::IndexOutOfBounds {
+                line: 4,
+                row: 3,
+                col: 1,
+                ..
+            }
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 x\n"),
+            IoError::InvalidNumber { line: 3, value } if value == "x"
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.0\n"),
+            IoError::EntryCount {
+                line: 3,
+                expected: 2,
+                found: 1
+            }
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix array real general\n1 1\n1.0\n2.0\n"),
+            IoError::EntryCount {
+                line: 4,
+                expected: 1,
+                found: 2
+            }
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix array real general\n1 1 1\n"),
+            IoError::RaggedRow { line: 2, .. }
+        ));
+
+        // the header size is not trusted, nothing is allocated for it up front
+        assert!(matches!(
+            read("%%MatrixMarket matrix array real general\n100000 100000\n1.0\n"),
+            IoError::EntryCount {
+                line: 3,
+                expected: 10000000000,
+                found: 1
+            }
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix array real general\n4294967296 4294967296\n"),
+            IoError::InvalidHeader { line: 2, .. }
+        ));
+    }
+
+    #[test]
+    fn test_read_array_symmetric() {
+        let text: &str = "%%MatrixMarket matrix array integer symmetric\n3 3\n1\n2\n3\n4\n5\n6\n";
+        let coo: CooMatrix<i32> = MatrixMarket::read_sparse(text.as_bytes()).unwrap();
+        let expected: Matrix<i32> = Matrix::new(&vec![vec![1, 2, 3], vec![2, 4, 5], vec![3, 5, 6]]);
+
+        assert!(coo.to_matrix().equals(&expected));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod csv;
+pub mod error;
+pub mod matrix_market;
+pub mod npy;
+pub mod npz;
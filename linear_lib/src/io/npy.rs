@@ -0,0 +1,405 @@
+use std::{
+    io::{self, Read, Write},
+    mem::size_of,
+    ops::{Add, Mul},
+};
+
+use crate::{
+    matrix::{matrix::Matrix, size::Size},
+    num::Num,
+    vector::{shape::Shape, vector::Vector},
+};
+
+use super::error::IoError;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+// Element types that have a numpy dtype.
+pub trait NpyElement: Mul<Output = Self> + Add<Output = Self> + Num + Copy {
+    // dtype without the byte order character, e.g. "f8"
+    const DESCR: &'static str;
+
+    fn from_bytes(bytes: &[u8], little_endian: bool) -> Self;
+    fn write_le(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_element {
+    ($($t:ty => $descr:literal)*) => {$(
+        impl NpyElement for $t {
+            const DESCR: &'static str = $descr;
+
+            fn from_bytes(bytes: &[u8], little_endian: bool) -> Self {
+                let bytes: [u8; size_of::<$t>()] = bytes.try_into().unwrap();
+                if little_endian {
+                    <$t>::from_le_bytes(bytes)
+                } else {
+                    <$t>::from_be_bytes(bytes)
+                }
+            }
+
+            fn write_le(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    )*};
+}
+
+impl_npy_element!(f32 => "f4" f64 => "f8" i32 => "i4" i64 => "i8");
+
+// Reader and writer for single numpy arrays (.npy), format versions 1.0 to 3.0.
+// Both byte orders and both C and Fortran order are read, arrays are written
+// little endian in C order.
+pub struct Npy {}
+
+// parsed .npy header
+struct Header {
+    shape: Vec<usize>,
+    fortran_order: bool,
+    little_endian: bool,
+}
+
+impl Npy {
+    // 2-d arrays, a 1-d array of length n is read as a 1 x n matrix
+    pub fn read_matrix<T: NpyElement, R: Read>(mut reader: R) -> Result<Matrix<T>, IoError> {
+        let header: Header = Self::read_header::<T, R>(&mut reader)?;
+        let (rows, cols) = match header.shape[..] {
+            [n] => (1, n),
+            [rows, cols] => (rows, cols),
+            _ => {
+                return Err(IoError::InvalidFormat {
+                    message: format!("expected a 2-d array, found shape {:?}", header.shape),
+                })
+            }
+        };
+
+        let len: usize = rows
+            .checked_mul(cols)
+            .ok_or_else(|| IoError::InvalidFormat {
+                message: format!("shape {:?} is too large", header.shape),
+            })?;
+        let data: Vec<T> = Self::read_data(&mut reader, &header, len)?;
+        let elements: Vec<T> = if header.fortran_order {
+            (0..len)
+                .map(|k| data[(k % cols) * rows + k / cols])
+                .collect()
+        } else {
+            data
+        };
+
+        Ok(Matrix {
+            elements,
+            size: Size::new(rows, cols),
+        })
+    }
+
+    // 1-d arrays are read as columns, n x 1 and 1 x n arrays keep their shape
+    pub fn read_vector<T: NpyElement, R: Read>(mut reader: R) -> Result<Vector<T>, IoError> {
+        let header: Header = Self::read_header::<T, R>(&mut reader)?;
+        let (n, shape) = match header.shape[..] {
+            [n] | [n, 1] => (n, Shape::Col),
+            [1, n] => (n, Shape::Row),
+            _ => {
+                return Err(IoError::InvalidFormat {
+                    message: format!("expected a 1-d array, found shape {:?}", header.shape),
+                })
+            }
+        };
+
+        Ok(Vector::new(
+            Self::read_data(&mut reader, &header, n)?,
+            shape,
+        ))
+    }
+
+    pub fn write_matrix<T: NpyElement, W: Write>(
+        matrix: &Matrix<T>,
+        writer: W,
+    ) -> Result<(), IoError> {
+        let shape: String = format!("({}, {})", matrix.size().rows(), matrix.size().cols());
+        Self::write_array::<T, W>(&matrix.elements, &shape, writer)
+    }
+
+    // written as a 1-d array, the shape of the vector is not stored
+    pub fn write_vector<T: NpyElement, W: Write>(
+        vector: &Vector<T>,
+        writer: W,
+    ) -> Result<(), IoError> {
+        let shape: String = format!("({},)", vector.size());
+        Self::write_array::<T, W>(&vector.elements, &shape, writer)
+    }
+
+    fn write_array<T: NpyElement, W: Write>(
+        elements: &[T],
+        shape: &str,
+        mut writer: W,
+    ) -> Result<(), IoError> {
+        let mut header: String = format!(
+            "{{'descr': '<{}', 'fortran_order': False, 'shape': {shape}, }}",
+            T::DESCR
+        );
+        // magic, version and header length take 10 bytes, the data starts 64 byte aligned
+        let padding: usize = 63 - (10 + header.len()) % 64;
+        header.push_str(&" ".repeat(padding));
+        header.push('\n');
+
+        let mut data: Vec<u8> = Vec::with_capacity(std::mem::size_of_val(elements));
+        for &e in elements {
+            e.write_le(&mut data);
+        }
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&(header.len() as u16).to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+        writer.write_all(&data)?;
+        writer.flush()?;
+        Ok(())
+    }
+
+    fn read_header<T: NpyElement, R: Read>(reader: &mut R) -> Result<Header, IoError> {
+        let mut preamble: [u8; 8] = [0; 8];
+        reader.read_exact(&mut preamble)?;
+        if &preamble[..6] != MAGIC {
+            return Err(IoError::InvalidFormat {
+                message: "missing \\x93NUMPY magic string".to_string(),
+            });
+        }
+
+        let length: usize = match preamble[6] {
+            1 => {
+                let mut bytes: [u8; 2] = [0; 2];
+                reader.read_exact(&mut bytes)?;
+                u16::from_le_bytes(bytes) as usize
+            }
+            2 | 3 => {
+                let mut bytes: [u8; 4] = [0; 4];
+                reader.read_exact(&mut bytes)?;
+                u32::from_le_bytes(bytes) as usize
+            }
+            version => {
+                return Err(IoError::InvalidFormat {
+                    message: format!("unknown version {version}.{}", preamble[7]),
+                })
+            }
+        };
+        let bytes: Vec<u8> = Self::read_bytes(reader, length)?;
+        let header: String = String::from_utf8_lossy(&bytes).into_owned();
+
+        let descr: &str = Self::quoted(Self::value(&header, "descr")?)?;
+        let (order, dtype) = descr.split_at(descr.len().min(1));
+        let little_endian: bool = match order {
+            "<" => true,
+            ">" => false,
+            "=" => cfg!(target_endian = "little"),
+            _ => {
+                return Err(IoError::DtypeMismatch {
+                    expected: format!("<{}", T::DESCR),
+                    found: descr.to_string(),
+                })
+            }
+        };
+        if dtype != T::DESCR {
+            return Err(IoError::DtypeMismatch {
+                expected: format!("<{}", T::DESCR),
+                found: descr.to_string(),
+            });
+        }
+
+        let fortran_order: bool = match Self::value(&header, "fortran_order")? {
+            v if v.starts_with("True") => true,
+            v if v.starts_with("False") => false,
+            v => {
+                return Err(IoError::InvalidFormat {
+                    message: format!("invalid fortran_order '{v}'"),
+                })
+            }
+        };
+
+        let shape: &str = Self::value(&header, "shape")?;
+        let end: usize = match (shape.starts_with('('), shape.find(')')) {
+            (true, Some(end)) => end,
+            _ => {
+                return Err(IoError::InvalidFormat {
+                    message: format!("invalid shape '{shape}'"),
+                })
+            }
+        };
+        let shape: Vec<usize> = shape[1..end]
+            .split(',')
+            .map(|d| d.trim())
+            .filter(|d| !d.is_empty())
+            .map(|d| {
+                d.parse::<usize>().map_err(|_| IoError::InvalidFormat {
+                    message: format!("invalid dimension '{d}'"),
+                })
+            })
+            .collect::<Result<Vec<usize>, IoError>>()?;
+
+        Ok(Header {
+            shape,
+            fortran_order,
+            little_endian,
+        })
+    }
+
+    fn read_data<T: NpyElement, R: Read>(
+        reader: &mut R,
+        header: &Header,
+        n: usize,
+    ) -> Result<Vec<T>, IoError> {
+        let len: usize = n
+            .checked_mul(size_of::<T>())
+            .ok_or_else(|| IoError::InvalidFormat {
+                message: format!("{n} elements are too many to read"),
+            })?;
+        let bytes: Vec<u8> = Self::read_bytes(reader, len)?;
+        Ok(bytes
+            .chunks_exact(size_of::<T>())
+            .map(|chunk| T::from_bytes(chunk, header.little_endian))
+            .collect())
+    }
+
+    // Exactly len bytes. The buffer only grows with the data actually read, so a
+    // length from a corrupt header can not allocate more than the file holds.
+    fn read_bytes<R: Read>(reader: &mut R, len: usize) -> Result<Vec<u8>, IoError> {
+        let mut bytes: Vec<u8> = vec![];
+        reader.by_ref().take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() < len {
+            return Err(IoError::Io(io::ErrorKind::UnexpectedEof.into()));
+        }
+        Ok(bytes)
+    }
+
+    // text following 'key': in the header dictionary
+    fn value<'a>(header: &'a str, key: &str) -> Result<&'a str, IoError> {
+        let position: Option<usize> = header
+            .find(&format!("'{key}'"))
+            .or_else(|| header.find(&format!("\"{key}\"")));
+        let rest: &str = match position {
+            Some(start) => &header[start + key.len() + 2..],
+            None => {
+                return Err(IoError::InvalidFormat {
+                    message: format!("header has no '{key}'"),
+                })
+            }
+        };
+        match rest.trim_start().strip_prefix(':') {
+            Some(value) => Ok(value.trim_start()),
+            None => Err(IoError::InvalidFormat {
+                message: format!("header has no value for '{key}'"),
+            }),
+        }
+    }
+
+    fn quoted(value: &str) -> Result<&str, IoError> {
+        let end: Option<usize> = match value.chars().next() {
+            Some(quote) if quote == '\'' || quote == '"' => value[1..].find(quote),
+            _ => None,
+        };
+        match end {
+            Some(end) => Ok(&value[1..end + 1]),
+            None => Err(IoError::InvalidFormat {
+                message: format!("expected a quoted string, found '{value}'"),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        io::error::IoError,
+        matrix::matrix::Matrix,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::Npy;
+
+    #[test]
+    fn test_round_trip() {
+        let matrix: Matrix<f64> = Matrix::new(&vec![vec![1.5, -2.0, 3.0], vec![0.0, 1e-300, 6.0]]);
+        let mut buffer: Vec<u8> = vec![];
+        Npy::write_matrix(&matrix, &mut buffer).unwrap();
+
+        // data starts 64 byte aligned after the header
+        assert_eq!(buffer.len(), 128 + 6 * 8);
+        assert_eq!(buffer[127], b'\n');
+        assert!(Npy::read_matrix::<f64, _>(buffer.as_slice())
+            .unwrap()
+            .equals(&matrix));
+
+        let vector: Vector<i32> = Vector::new(vec![4, -5, 6], Shape::Col);
+        let mut buffer: Vec<u8> = vec![];
+        Npy::write_vector(&vector, &mut buffer).unwrap();
+        assert!(Npy::read_vector::<i32, _>(buffer.as_slice())
+            .unwrap()
+            .equals(&vector));
+    }
+
+    #[test]
+    fn test_read_fortran_big_endian() {
+        // np.asfortranarray(np.arange(6, dtype='>i8').reshape(2, 3)) saved in version 2.0
+        let mut file: Vec<u8> = b"\x93NUMPY\x02\x00".to_vec();
+        let header: &str = "{'descr': '>i8', 'fortran_order': True, 'shape': (2, 3), }\n";
+        file.extend_from_slice(&(header.len() as u32).to_le_bytes());
+        file.extend_from_slice(header.as_bytes());
+        for val in [0_i64, 3, 1, 4, 2, 5] {
+            file.extend_from_slice(&val.to_be_bytes());
+        }
+
+        let matrix: Matrix<i64> = Npy::read_matrix(file.as_slice()).unwrap();
+        assert!(matrix.equals(&Matrix::new(&vec![vec![0, 1, 2], vec![3, 4, 5]])));
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut buffer: Vec<u8> = vec![];
+        Npy::write_matrix(&Matrix::new(&vec![vec![1.0_f32, 2.0]]), &mut buffer).unwrap();
+
+        assert!(matches!(
+            Npy::read_matrix::<f64, _>(buffer.as_slice()),
+            Err(IoError::DtypeMismatch { expected, found }) if expected == "<f8" && found == "<f4"
+        ));
+        assert!(matches!(
+            Npy::read_matrix::<f32, _>(&buffer[..buffer.len() - 1]),
+            Err(IoError::Io(_))
+        ));
+        assert!(matches!(
+            Npy::read_matrix::<f32, _>(&b"PK\x03\x04 not npy"[..]),
+            Err(IoError::InvalidFormat { .. })
+        ));
+
+        // header cut off after the key, and a descr without a closing quote
+        for header in ["{'descr':", "{'descr': '<f4"] {
+            let mut file: Vec<u8> = b"\x93NUMPY\x01\x00".to_vec();
+            file.extend_from_slice(&(header.len() as u16).to_le_bytes());
+            file.extend_from_slice(header.as_bytes());
+            assert!(matches!(
+                Npy::read_matrix::<f32, _>(file.as_slice()),
+                Err(IoError::InvalidFormat { .. })
+            ));
+        }
+
+        // shapes are not trusted: the product overflows, or there is no data behind it
+        let file = |shape: &str| {
+            let header: String =
+                format!("{{'descr': '<f8', 'fortran_order': False, 'shape': {shape}, }}");
+            let mut file: Vec<u8> = b"\x93NUMPY\x01\x00".to_vec();
+            file.extend_from_slice(&(header.len() as u16).to_le_bytes());
+            file.extend_from_slice(header.as_bytes());
+            file
+        };
+        assert!(matches!(
+            Npy::read_matrix::<f64, _>(file("(4294967296, 4294967296)").as_slice()),
+            Err(IoError::InvalidFormat { .. })
+        ));
+        assert!(matches!(
+            Npy::read_vector::<f64, _>(file(&format!("({},)", usize::MAX / 2)).as_slice()),
+            Err(IoError::InvalidFormat { .. })
+        ));
+        assert!(matches!(
+            Npy::read_matrix::<f64, _>(file("(100000, 100000)").as_slice()),
+            Err(IoError::Io(_))
+        ));
+    }
+}
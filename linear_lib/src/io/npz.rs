@@ -0,0 +1,133 @@
+use std::io::{Read, Seek, Write};
+
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::{matrix::matrix::Matrix, vector::vector::Vector};
+
+use super::{
+    error::IoError,
+    npy::{Npy, NpyElement},
+};
+
+// Named arrays in a numpy .npz archive, as written by np.savez and
+// np.savez_compressed. Every array is a "<name>.npy" member of a zip file.
+pub struct NpzReader<R: Read + Seek> {
+    archive: ZipArchive<R>,
+}
+
+impl<R: Read + Seek> NpzReader<R> {
+    pub fn new(reader: R) -> Result<Self, IoError> {
+        Ok(NpzReader {
+            archive: ZipArchive::new(reader)?,
+        })
+    }
+
+    // array names without the .npy suffix, in archive order
+    pub fn names(&self) -> Vec<String> {
+        self.archive
+            .file_names()
+            .map(|name| name.strip_suffix(".npy").unwrap_or(name).to_string())
+            .collect()
+    }
+
+    pub fn matrix<T: NpyElement>(&mut self, name: &str) -> Result<Matrix<T>, IoError> {
+        Npy::read_matrix(self.member(name)?)
+    }
+
+    pub fn vector<T: NpyElement>(&mut self, name: &str) -> Result<Vector<T>, IoError> {
+        Npy::read_vector(self.member(name)?)
+    }
+
+    // the zip member type is not named, its generics differ between zip 2.x releases
+    fn member(&mut self, name: &str) -> Result<impl Read + '_, IoError> {
+        let file_name: String = format!("{name}.npy");
+        if !self.archive.file_names().any(|n| n == file_name) {
+            return Err(IoError::MissingArray {
+                name: name.to_string(),
+            });
+        }
+        Ok(self.archive.by_name(&file_name)?)
+    }
+}
+
+pub struct NpzWriter<W: Write + Seek> {
+    zip: ZipWriter<W>,
+    options: SimpleFileOptions,
+}
+
+impl<W: Write + Seek> NpzWriter<W> {
+    // compressed matches np.savez_compressed, otherwise members are stored like np.savez
+    pub fn new(writer: W, compressed: bool) -> Self {
+        let method: CompressionMethod = if compressed {
+            CompressionMethod::Deflated
+        } else {
+            CompressionMethod::Stored
+        };
+        NpzWriter {
+            zip: ZipWriter::new(writer),
+            options: SimpleFileOptions::default()
+                .compression_method(method)
+                .large_file(false),
+        }
+    }
+
+    pub fn add_matrix<T: NpyElement>(
+        &mut self,
+        name: &str,
+        matrix: &Matrix<T>,
+    ) -> Result<(), IoError> {
+        self.zip.start_file(format!("{name}.npy"), self.options)?;
+        Npy::write_matrix(matrix, &mut self.zip)
+    }
+
+    pub fn add_vector<T: NpyElement>(
+        &mut self,
+        name: &str,
+        vector: &Vector<T>,
+    ) -> Result<(), IoError> {
+        self.zip.start_file(format!("{name}.npy"), self.options)?;
+        Npy::write_vector(vector, &mut self.zip)
+    }
+
+    // writes the zip directory, the archive is incomplete until this is called
+    pub fn finish(self) -> Result<W, IoError> {
+        Ok(self.zip.finish()?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use crate::{
+        io::error::IoError,
+        matrix::matrix::Matrix,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::{NpzReader, NpzWriter};
+
+    #[test]
+    fn test_round_trip() {
+        let weights: Matrix<f32> = Matrix::new(&vec![vec![0.5, -1.0], vec![2.0, 0.25]]);
+        let bias: Vector<f64> = Vector::new(vec![0.1, 0.2], Shape::Col);
+
+        for compressed in [false, true] {
+            let mut writer: NpzWriter<Cursor<Vec<u8>>> =
+                NpzWriter::new(Cursor::new(vec![]), compressed);
+            writer.add_matrix("weights", &weights).unwrap();
+            writer.add_vector("bias", &bias).unwrap();
+            let buffer: Cursor<Vec<u8>> = writer.finish().unwrap();
+
+            let mut reader: NpzReader<Cursor<Vec<u8>>> =
+                NpzReader::new(Cursor::new(buffer.into_inner())).unwrap();
+            assert_eq!(reader.names(), vec!["weights", "bias"]);
+            assert!(reader.matrix::<f32>("weights").unwrap().equals(&weights));
+            assert!(reader.vector::<f64>("bias").unwrap().equals(&bias));
+            assert!(matches!(
+                reader.matrix::<f32>("missing"),
+                Err(IoError::MissingArray { name }) if name == "missing"
+            ));
+        }
+    }
+}
@@ -1,6 +1,12 @@
+pub mod complex;
+pub mod error;
 pub mod generator;
+pub mod io;
 pub mod matrix;
 pub mod num;
 pub mod print_helper;
 pub mod printer;
+pub mod rational;
+pub mod renderer;
+pub mod sparse;
 pub mod vector;
@@ -0,0 +1,289 @@
+use std::ops::{Add, Div, Mul};
+
+use crate::{error::LinearError, num::Num, vector::vector::Vector};
+
+use super::{
+    matrix::Matrix,
+    size::Size,
+    view::{AsMatrixView, MatrixView},
+};
+
+impl<T> Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    pub fn identity(n: usize) -> Matrix<T> {
+        Self::from_fn(n, n, |i, j| if i == j { T::one() } else { T::zero() })
+    }
+
+    pub fn zeros(rows: usize, cols: usize) -> Matrix<T> {
+        Self::filled(rows, cols, T::zero())
+    }
+
+    pub fn ones(rows: usize, cols: usize) -> Matrix<T> {
+        Self::filled(rows, cols, T::one())
+    }
+
+    // square matrix with the elements of the vector on its diagonal
+    pub fn from_diagonal(diagonal: &Vector<T>) -> Matrix<T> {
+        let n: usize = diagonal.size();
+        Self::from_fn(n, n, |i, j| {
+            if i == j {
+                diagonal.elements[i].clone()
+            } else {
+                T::zero()
+            }
+        })
+    }
+
+    // element (i, j) is f(i, j)
+    pub fn from_fn<F: Fn(usize, usize) -> T>(rows: usize, cols: usize, f: F) -> Matrix<T> {
+        Matrix {
+            elements: (0..rows * cols).map(|k| f(k / cols, k % cols)).collect(),
+            size: Size::new(rows, cols),
+        }
+    }
+
+    // elements row after row, the layout Matrix stores them in
+    pub fn from_row_major(rows: usize, cols: usize, elements: Vec<T>) -> Matrix<T> {
+        Self::check_count(rows, cols, elements.len());
+        Matrix {
+            elements,
+            size: Size::new(rows, cols),
+        }
+    }
+
+    // elements column after column, as Fortran, LAPACK and numpy order='F' store them
+    pub fn from_col_major(rows: usize, cols: usize, elements: Vec<T>) -> Matrix<T> {
+        Self::check_count(rows, cols, elements.len());
+        Self::from_fn(rows, cols, |i, j| elements[j * rows + i].clone())
+    }
+
+    // [A B ...], the blocks need the same number of rows. Blocks may be matrices, views
+    // and vectors mixed, vectors are stacked as they are shaped.
+    pub fn hstack(blocks: &[&dyn AsMatrixView<T>]) -> Result<Matrix<T>, LinearError> {
+        let views: Vec<MatrixView<'_, T>> = blocks.iter().map(|b| b.as_view()).collect();
+        let rows: usize = views.first().map_or(0, |v| v.size().rows());
+        Self::check_blocks(&views, |v| v.size().rows() == rows)?;
+
+        let cols: usize = views.iter().map(|v| v.size().cols()).sum();
+        let mut elements: Vec<T> = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for view in views.iter() {
+                elements.extend(view.row(i).iter().cloned());
+            }
+        }
+
+        Ok(Matrix {
+            elements,
+            size: Size::new(rows, cols),
+        })
+    }
+
+    // [A; B; ...], the blocks need the same number of columns
+    pub fn vstack(blocks: &[&dyn AsMatrixView<T>]) -> Result<Matrix<T>, LinearError> {
+        let views: Vec<MatrixView<'_, T>> = blocks.iter().map(|b| b.as_view()).collect();
+        let cols: usize = views.first().map_or(0, |v| v.size().cols());
+        Self::check_blocks(&views, |v| v.size().cols() == cols)?;
+
+        let rows: usize = views.iter().map(|v| v.size().rows()).sum();
+        let mut elements: Vec<T> = Vec::with_capacity(rows * cols);
+        for view in views.iter() {
+            elements.extend(view.iter().cloned());
+        }
+
+        Ok(Matrix {
+            elements,
+            size: Size::new(rows, cols),
+        })
+    }
+
+    // V[i][j] = x_i^j, powers increase from left to right
+    pub fn vandermonde(points: &Vector<T>, cols: usize) -> Matrix<T> {
+        let mut elements: Vec<T> = Vec::with_capacity(points.size() * cols);
+        for x in points.elements.iter() {
+            let mut power: T = T::one();
+            for _ in 0..cols {
+                elements.push(power.clone());
+                power *= x.clone();
+            }
+        }
+
+        Matrix {
+            elements,
+            size: Size::new(points.size(), cols),
+        }
+    }
+
+    // Constant along every diagonal, T[i][j] = col[i - j] below and row[j - i] above.
+    // The diagonal is taken from col, row[0] is ignored.
+    pub fn toeplitz(col: &Vector<T>, row: &Vector<T>) -> Matrix<T> {
+        assert!(col.size() > 0, "the first column must not be empty");
+        Self::from_fn(col.size(), row.size(), |i, j| {
+            if i >= j {
+                col.elements[i - j].clone()
+            } else {
+                row.elements[j - i].clone()
+            }
+        })
+    }
+
+    // n x n matrix from the n - 1 sub-diagonal, n diagonal and n - 1 super-diagonal elements
+    pub fn tridiagonal(lower: &Vector<T>, diagonal: &Vector<T>, upper: &Vector<T>) -> Matrix<T> {
+        let n: usize = diagonal.size();
+        assert!(
+            lower.size() + 1 == n.max(1) && upper.size() + 1 == n.max(1),
+            "a {n}x{n} tridiagonal matrix needs {} elements below and above the diagonal",
+            n.saturating_sub(1)
+        );
+        Self::from_fn(n, n, |i, j| match j as isize - i as isize {
+            -1 => lower.elements[j].clone(),
+            0 => diagonal.elements[i].clone(),
+            1 => upper.elements[i].clone(),
+            _ => T::zero(),
+        })
+    }
+
+    fn filled(rows: usize, cols: usize, val: T) -> Matrix<T> {
+        Matrix {
+            elements: vec![val; rows * cols],
+            size: Size::new(rows, cols),
+        }
+    }
+
+    fn check_count(rows: usize, cols: usize, count: usize) {
+        assert!(
+            rows * cols == count,
+            "a {rows}x{cols} matrix needs {} elements, got {count}",
+            rows * cols
+        );
+    }
+
+    // the first block that does not fit is reported against the first one
+    fn check_blocks<F>(views: &[MatrixView<'_, T>], fits: F) -> Result<(), LinearError>
+    where
+        F: Fn(&MatrixView<'_, T>) -> bool,
+    {
+        match views.iter().find(|v| !fits(v)) {
+            Some(view) => Err(LinearError::DimensionMismatch {
+                left: views[0].size(),
+                right: view.size(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Div<Output = T> + Num,
+{
+    // H[i][j] = 1 / (i + j + 1), the classic badly conditioned test matrix
+    pub fn hilbert(n: usize) -> Matrix<T> {
+        Self::from_fn(n, n, |i, j| T::one() / T::from_usize(i + j + 1))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::LinearError,
+        matrix::{matrix::Matrix, size::Size},
+        rational::Rational,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    #[test]
+    fn test_basic() {
+        assert!(Matrix::<i32>::identity(3).equals(&Matrix::new(&vec![
+            vec![1, 0, 0],
+            vec![0, 1, 0],
+            vec![0, 0, 1]
+        ])));
+        assert!(Matrix::<f64>::zeros(2, 3).equals(&Matrix::new(&vec![vec![0.0; 3]; 2])));
+        assert!(Matrix::<u8>::ones(3, 1).equals(&Matrix::new(&vec![vec![1]; 3])));
+
+        let diagonal: Vector<i32> = Vector::new(vec![2, -1], Shape::Col);
+        assert!(
+            Matrix::from_diagonal(&diagonal).equals(&Matrix::new(&vec![vec![2, 0], vec![0, -1]]))
+        );
+
+        let matrix: Matrix<usize> = Matrix::from_fn(2, 3, |i, j| 10 * i + j);
+        assert!(matrix.equals(&Matrix::new(&vec![vec![0, 1, 2], vec![10, 11, 12]])));
+        assert!(Matrix::from_row_major(2, 3, vec![0, 1, 2, 10, 11, 12]).equals(&matrix));
+        assert!(Matrix::from_col_major(2, 3, vec![0, 10, 1, 11, 2, 12]).equals(&matrix));
+    }
+
+    #[test]
+    #[should_panic(expected = "a 2x2 matrix needs 4 elements, got 3")]
+    fn test_from_row_major_count() {
+        Matrix::from_row_major(2, 2, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_stack() {
+        let a: Matrix<i32> = Matrix::new(&vec![vec![1, 2], vec![3, 4]]);
+        let b: Matrix<i32> = Matrix::new(&vec![vec![5], vec![6]]);
+
+        let x: Vector<i32> = Vector::new(vec![7, 8], Shape::Col);
+        let h: Matrix<i32> = Matrix::hstack(&[&a, &b.view(), &a.col(0), &x]).unwrap();
+        assert!(h.equals(&Matrix::new(&vec![
+            vec![1, 2, 5, 1, 7],
+            vec![3, 4, 6, 3, 8]
+        ])));
+
+        let row: Vector<i32> = Vector::new(vec![9, 0], Shape::Row);
+        let v: Matrix<i32> = Matrix::vstack(&[&a, &a.transposed(), &row]).unwrap();
+        assert!(v.equals(&Matrix::new(&vec![
+            vec![1, 2],
+            vec![3, 4],
+            vec![1, 3],
+            vec![2, 4],
+            vec![9, 0]
+        ])));
+
+        assert_eq!(
+            Matrix::vstack(&[&a, &b]).err(),
+            Some(LinearError::DimensionMismatch {
+                left: Size::new(2, 2),
+                right: Size::new(2, 1)
+            })
+        );
+        assert_eq!(*Matrix::<i32>::hstack(&[]).unwrap().size(), Size::new(0, 0));
+    }
+
+    #[test]
+    fn test_special() {
+        let hilbert: Matrix<Rational> = Matrix::hilbert(2);
+        assert!(hilbert.equals(&Matrix::new(&vec![
+            vec![Rational::from(1), Rational::new(1, 2)],
+            vec![Rational::new(1, 2), Rational::new(1, 3)]
+        ])));
+
+        let points: Vector<i64> = Vector::new(vec![2, -1, 3], Shape::Col);
+        assert!(Matrix::vandermonde(&points, 4).equals(&Matrix::new(&vec![
+            vec![1, 2, 4, 8],
+            vec![1, -1, 1, -1],
+            vec![1, 3, 9, 27]
+        ])));
+
+        let col: Vector<i32> = Vector::new(vec![1, 2, 3], Shape::Col);
+        let row: Vector<i32> = Vector::new(vec![0, 4, 5, 6], Shape::Row);
+        assert!(Matrix::toeplitz(&col, &row).equals(&Matrix::new(&vec![
+            vec![1, 4, 5, 6],
+            vec![2, 1, 4, 5],
+            vec![3, 2, 1, 4]
+        ])));
+
+        let tridiagonal: Matrix<f64> = Matrix::tridiagonal(
+            &Vector::new(vec![-1.0; 2], Shape::Col),
+            &Vector::new(vec![4.0; 3], Shape::Col),
+            &Vector::new(vec![-2.0; 2], Shape::Col),
+        );
+        assert!(tridiagonal.equals(&Matrix::new(&vec![
+            vec![4.0, -2.0, 0.0],
+            vec![-1.0, 4.0, -2.0],
+            vec![0.0, -1.0, 4.0]
+        ])));
+    }
+}
@@ -0,0 +1,309 @@
+use std::ops::{Add, Div, Mul};
+
+use crate::{
+    error::LinearError,
+    num::{argmax_of, Num},
+    vector::{shape::Shape, vector::Vector},
+};
+
+use super::{
+    matrix::Matrix,
+    size::Size,
+    view::{AsMatrixView, MatrixView},
+};
+
+// Element-wise and row/column-wise operations. Reductions over rows return a column
+// vector with one element per row, reductions over columns a row vector.
+impl<T> Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    // new matrix with f applied to every element, the element type may change
+    pub fn map<U, F: FnMut(T) -> U>(&self, f: F) -> Matrix<U> {
+        Matrix {
+            elements: self.elements.iter().cloned().map(f).collect(),
+            size: self.size().clone(),
+        }
+    }
+
+    pub fn map_inplace<F: FnMut(T) -> T>(&mut self, mut f: F) {
+        for e in self.elements.iter_mut() {
+            *e = f(e.clone());
+        }
+    }
+
+    // f applied to the elements at the same position of both matrices
+    pub fn zip_with<U, M, F>(&self, right: &M, mut f: F) -> Result<Matrix<U>, LinearError>
+    where
+        M: AsMatrixView<T>,
+        F: FnMut(T, T) -> U,
+    {
+        let right: MatrixView<'_, T> = right.as_view();
+        if *self.size() != right.size() {
+            return Err(LinearError::DimensionMismatch {
+                left: self.size().clone(),
+                right: right.size(),
+            });
+        }
+
+        Ok(Matrix {
+            elements: self
+                .elements
+                .iter()
+                .zip(right.iter())
+                .map(|(a, b)| f(a.clone(), b.clone()))
+                .collect(),
+            size: self.size().clone(),
+        })
+    }
+
+    // elements in row-major order
+    pub fn fold<A, F: FnMut(A, &T) -> A>(&self, init: A, f: F) -> A {
+        self.elements.iter().fold(init, f)
+    }
+
+    // Every row is passed to f as a row vector, the results become the rows of the new
+    // matrix. Panics if f returns vectors of different sizes.
+    pub fn apply_rows<F: FnMut(&Vector<T>) -> Vector<T>>(&self, mut f: F) -> Matrix<T> {
+        let cols: usize = self.size().cols();
+        let results: Vec<Vector<T>> = (0..self.size().rows())
+            .map(|r| {
+                let row: Vec<T> = self.elements[r * cols..(r + 1) * cols].to_vec();
+                f(&Vector::new(row, Shape::Row))
+            })
+            .collect();
+        let width: usize = Self::common_size(&results, "apply_rows");
+
+        Matrix {
+            elements: results.into_iter().flat_map(|v| v.elements).collect(),
+            size: Size::new(self.size().rows(), width),
+        }
+    }
+
+    // Every column is passed to f as a column vector, the results become the columns of
+    // the new matrix. Panics if f returns vectors of different sizes.
+    pub fn apply_cols<F: FnMut(&Vector<T>) -> Vector<T>>(&self, mut f: F) -> Matrix<T> {
+        let results: Vec<Vector<T>> = (0..self.size().cols())
+            .map(|c| {
+                f(&Vector::new(
+                    self.col(c).iter().cloned().collect(),
+                    Shape::Col,
+                ))
+            })
+            .collect();
+        let height: usize = Self::common_size(&results, "apply_cols");
+
+        Matrix::from_fn(height, results.len(), |i, j| results[j].elements[i].clone())
+    }
+
+    // element-wise product A ∘ B
+    pub fn hadamard_product<M: AsMatrixView<T>>(
+        &self,
+        right: &M,
+    ) -> Result<Matrix<T>, LinearError> {
+        self.zip_with(right, |a, b| a * b)
+    }
+
+    pub fn hadamard_division<M>(&self, right: &M) -> Result<Matrix<T>, LinearError>
+    where
+        M: AsMatrixView<T>,
+        T: Div<Output = T>,
+    {
+        self.zip_with(right, |a, b| a / b)
+    }
+
+    // sum of every row
+    pub fn sum_rows(&self) -> Vector<T> {
+        let elements: Vec<T> = (0..self.size().rows()).map(|r| self.row(r).sum()).collect();
+        Vector::new(elements, Shape::Col)
+    }
+
+    // sum of every column
+    pub fn sum_cols(&self) -> Vector<T> {
+        let elements: Vec<T> = (0..self.size().cols()).map(|c| self.col(c).sum()).collect();
+        Vector::new(elements, Shape::Row)
+    }
+
+    // mean of every row, None if the rows are empty
+    pub fn mean_rows(&self) -> Option<Vector<T>>
+    where
+        T: Div<Output = T>,
+    {
+        if self.size().cols() == 0 {
+            return None;
+        }
+        let cols: T = T::from_usize(self.size().cols());
+        Some(self.sum_rows().map(|s| s / cols.clone()))
+    }
+
+    // mean of every column, None if the columns are empty
+    pub fn mean_cols(&self) -> Option<Vector<T>>
+    where
+        T: Div<Output = T>,
+    {
+        if self.size().rows() == 0 {
+            return None;
+        }
+        let rows: T = T::from_usize(self.size().rows());
+        Some(self.sum_cols().map(|s| s / rows.clone()))
+    }
+
+    // column of the largest element of every row, the first one on ties and NaN skipped,
+    // None if a row has no ordered element
+    pub fn argmax_rows(&self) -> Option<Vec<usize>> {
+        (0..self.size().rows())
+            .map(|r| argmax_of(self.row(r).iter()))
+            .collect()
+    }
+
+    // row of the largest element of every column, the first one on ties and NaN skipped,
+    // None if a column has no ordered element
+    pub fn argmax_cols(&self) -> Option<Vec<usize>> {
+        (0..self.size().cols())
+            .map(|c| argmax_of(self.col(c).iter()))
+            .collect()
+    }
+
+    fn common_size(results: &[Vector<T>], name: &str) -> usize {
+        let size: usize = results.first().map_or(0, |v| v.size());
+        if let Some(i) = results.iter().position(|v| v.size() != size) {
+            panic!(
+                "{name}: result {i} has {} elements, result 0 has {size}",
+                results[i].size()
+            );
+        }
+        size
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::LinearError,
+        matrix::{matrix::Matrix, size::Size},
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    #[test]
+    fn test_map() {
+        let mut matrix: Matrix<i32> = Matrix::new(&vec![vec![1, -2], vec![3, -4]]);
+        let offset: i32 = 10;
+
+        assert!(matrix
+            .map(|x| x + offset)
+            .equals(&Matrix::new(&vec![vec![11, 8], vec![13, 6]])));
+        assert!(matrix
+            .map(|x| x as f64 / 2.0)
+            .equals(&Matrix::new(&vec![vec![0.5, -1.0], vec![1.5, -2.0]])));
+
+        matrix.map_inplace(|x| x.abs());
+        assert!(matrix.equals(&Matrix::new(&vec![vec![1, 2], vec![3, 4]])));
+        assert_eq!(matrix.fold(0, |count, &x| count + (x % 2 == 0) as usize), 2);
+    }
+
+    #[test]
+    fn test_zip_with() {
+        let a: Matrix<f64> = Matrix::new(&vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+        let b: Matrix<f64> = Matrix::new(&vec![vec![2.0, 4.0], vec![6.0, 8.0]]);
+
+        assert!(a
+            .hadamard_product(&b)
+            .unwrap()
+            .equals(&Matrix::new(&vec![vec![2.0, 8.0], vec![18.0, 32.0]])));
+        assert!(b
+            .hadamard_division(&a)
+            .unwrap()
+            .equals(&Matrix::new(&vec![vec![2.0; 2]; 2])));
+        assert!(a
+            .zip_with(&b, |x, y| x < y)
+            .unwrap()
+            .elements
+            .iter()
+            .all(|&less| less));
+        assert_eq!(
+            a.hadamard_product(&a.row(0)).err(),
+            Some(LinearError::DimensionMismatch {
+                left: Size::new(2, 2),
+                right: Size::new(1, 2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        let matrix: Matrix<f64> = Matrix::new(&vec![vec![1.0, 3.0], vec![2.0, 2.0]]);
+
+        // normalize every row to sum 1
+        let normalized: Matrix<f64> = matrix.apply_rows(|row| {
+            let sum: f64 = row.sum();
+            row.map(|x| x / sum)
+        });
+        assert!(normalized.equals(&Matrix::new(&vec![vec![0.25, 0.75], vec![0.5, 0.5]])));
+
+        // append the sum to every column
+        let extended: Matrix<f64> = matrix.apply_cols(|col| {
+            let mut elements: Vec<f64> = col.elements().to_vec();
+            elements.push(col.sum());
+            Vector::new(elements, Shape::Col)
+        });
+        assert!(extended.equals(&Matrix::new(&vec![
+            vec![1.0, 3.0],
+            vec![2.0, 2.0],
+            vec![3.0, 5.0]
+        ])));
+    }
+
+    #[test]
+    #[should_panic(expected = "apply_rows: result 1 has 2 elements, result 0 has 1")]
+    fn test_apply_sizes() {
+        let matrix: Matrix<i32> = Matrix::new(&vec![vec![1, 2], vec![3, 4]]);
+        matrix.apply_rows(|row| {
+            Vector::new(
+                row.elements()[..row.max() as usize / 2].to_vec(),
+                Shape::Row,
+            )
+        });
+    }
+
+    #[test]
+    fn test_reductions() {
+        let matrix: Matrix<f64> = Matrix::new(&vec![vec![1.0, 5.0, 3.0], vec![4.0, 2.0, 6.0]]);
+
+        let sums: Vector<f64> = matrix.sum_rows();
+        assert_eq!(sums.elements(), &[9.0, 12.0]);
+        assert_eq!(*sums.shape(), Shape::Col);
+        assert_eq!(matrix.mean_rows().unwrap().elements(), &[3.0, 4.0]);
+
+        let means: Vector<f64> = matrix.mean_cols().unwrap();
+        assert_eq!(means.elements(), &[2.5, 3.5, 4.5]);
+        assert_eq!(*means.shape(), Shape::Row);
+        assert_eq!(matrix.sum_cols().elements(), &[5.0, 7.0, 9.0]);
+
+        assert_eq!(matrix.argmax_rows(), Some(vec![1, 2]));
+        assert_eq!(matrix.argmax_cols(), Some(vec![1, 0, 1]));
+        assert_eq!(Matrix::<i32>::ones(2, 3).argmax_rows(), Some(vec![0, 0]));
+    }
+
+    #[test]
+    fn test_reductions_empty() {
+        // 2 empty rows, integer division by the zero length must not happen
+        let matrix: Matrix<i32> = Matrix::new(&vec![vec![], vec![]]);
+        assert!(matrix.mean_rows().is_none());
+        assert_eq!(matrix.argmax_rows(), None);
+        assert_eq!(matrix.sum_rows().elements(), &[0, 0]);
+        assert_eq!(matrix.mean_cols().unwrap().size(), 0);
+        assert_eq!(matrix.argmax_cols(), Some(vec![]));
+    }
+
+    #[test]
+    fn test_argmax_nan() {
+        let matrix: Matrix<f64> =
+            Matrix::new(&vec![vec![5.0, f64::NAN, 1.0], vec![f64::NAN, 2.0, 0.0]]);
+        assert_eq!(matrix.argmax_rows(), Some(vec![0, 1]));
+        assert_eq!(matrix.argmax_cols(), Some(vec![0, 1, 0]));
+
+        // a row without any ordered element has no argmax
+        let matrix: Matrix<f64> = Matrix::new(&vec![vec![1.0, 2.0], vec![f64::NAN; 2]]);
+        assert_eq!(matrix.argmax_rows(), None);
+    }
+}
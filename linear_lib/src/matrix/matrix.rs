@@ -1,12 +1,19 @@
-use std::ops::{Add, Div, Mul};
+use std::ops::{Add, Div, Mul, Sub};
+
+use rand::Rng;
 
 use crate::generator::Generator;
-use crate::num::Num;
-use crate::vector::shape::Shape;
+use crate::num::{max_of, min_of, Float, Num};
 use crate::vector::vector::Vector;
 
+use crate::error::LinearError;
+
 use super::size::Size;
+use super::view::AsMatrixView;
 
+// Deserializing checks that the number of elements matches the size, see below.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Matrix<T> {
     pub(crate) elements: Vec<T>,
     pub(crate) size: Size,
@@ -16,6 +23,8 @@ impl<T> Matrix<T>
 where
     T: Mul<Output = T> + Add<Output = T> + Num,
 {
+    // takes &Vec so callers can keep writing Matrix::new(&vec![...])
+    #[allow(clippy::ptr_arg)]
     pub fn new(data: &Vec<Vec<T>>) -> Self {
         Matrix {
             elements: Self::to_row_major(data),
@@ -23,18 +32,38 @@ where
         }
     }
 
+    // uniform in [low, high), from rand::thread_rng()
     pub fn random(low: T, high: T, rows: usize, cols: usize) -> Matrix<T> {
-        let mut elements: Vec<T> = vec![];
-        for _row in 0..rows {
-            elements.append(&mut Generator::random_elements(low, high, cols));
-        }
+        Self::random_with(&mut rand::thread_rng(), low, high, rows, cols)
+    }
 
+    // uniform in [low, high), pass a seeded rng for reproducible matrices
+    pub fn random_with<R: Rng + ?Sized>(
+        rng: &mut R,
+        low: T,
+        high: T,
+        rows: usize,
+        cols: usize,
+    ) -> Matrix<T> {
         Matrix {
-            elements,
+            elements: Generator::uniform(rng, low, high, rows * cols),
             size: Size::new(rows, cols),
         }
     }
 
+    // ones with probability p, zeros otherwise
+    pub fn random_bernoulli<R: Rng + ?Sized>(
+        rng: &mut R,
+        p: f64,
+        rows: usize,
+        cols: usize,
+    ) -> Result<Matrix<T>, LinearError> {
+        Ok(Matrix {
+            elements: Generator::bernoulli(rng, p, rows * cols)?,
+            size: Size::new(rows, cols),
+        })
+    }
+
     pub fn transpose(&self) -> Matrix<T> {
         let mut elements: Vec<T> = vec![T::zero(); self.elements.len()];
         let rows: usize = self.size().rows();
@@ -42,80 +71,60 @@ where
 
         for i in 0..rows {
             for j in 0..cols {
-                elements[j * rows + i] = self.elements[i * cols + j];
+                elements[j * rows + i] = self.elements[i * cols + j].clone();
             }
         }
 
         Matrix {
             elements,
-            size: Size::new(rows, cols),
+            size: Size::new(cols, rows),
         }
     }
 
-    pub fn product(&self, right: &Matrix<T>) -> Result<Matrix<T>, &'static str> {
-        if self.size().cols() * right.size().cols() != right.elements.len() {
-            return Err("product is not defined");
+    pub fn conjugate(&self) -> Matrix<T> {
+        Matrix {
+            elements: self.elements.iter().map(|e| e.conjugate()).collect(),
+            size: self.size().clone(),
         }
+    }
 
-        let elements: Vec<T> = vec![T::zero(); self.size().rows() * right.size().cols()];
-        let rows = elements.len() / right.size().cols();
-        let mut matrix = Matrix {
-            elements,
-            size: Size::new(rows, right.size().cols()),
-        };
-
-        for row in 0..rows {
-            for col in 0..matrix.size().cols() {
-                let mut cell: T = T::zero();
-                for i in 0..right.size().rows() {
-                    cell += *self.get(row, i).unwrap() * *right.get(i, col).unwrap();
-                }
-                matrix.set(row, col, cell);
-            }
-        }
+    // A^H, equal to transpose for real matrices
+    pub fn conjugate_transpose(&self) -> Matrix<T> {
+        self.transpose().conjugate()
+    }
 
-        Ok(matrix)
+    // accepts owned matrices as well as views, see MatrixView
+    pub fn product<M: AsMatrixView<T>>(&self, right: &M) -> Result<Matrix<T>, LinearError> {
+        self.view().product(right)
     }
 
-    pub fn vector_product(&self, vector: &Vector<T>) -> Result<Vector<T>, &'static str> {
-        if self.size().cols() != vector.size() {
-            return Err("product is not defined");
-        }
+    pub fn vector_product(&self, vector: &Vector<T>) -> Result<Vector<T>, LinearError> {
+        self.view().vector_product(vector)
+    }
 
-        let mut elements: Vec<T> = vec![T::zero(); self.size().rows()];
-        for (r, element) in elements.iter_mut().enumerate().take(self.size().rows()) {
-            for c in 0..self.size().cols() {
-                *element += *self.get(r, c).unwrap() * *vector.get(c).unwrap()
-            }
-        }
+    pub fn norm_one(&self) -> T {
+        self.view().norm_one()
+    }
 
-        Ok(Vector::new(elements, Shape::Col))
+    pub fn norm_inf(&self) -> T {
+        self.view().norm_inf()
     }
 
-    pub fn add(&self, right: &Matrix<T>) -> Result<Matrix<T>, &'static str> {
-        match self.add_with_coeficient(right, T::one()) {
-            Ok(elements) => Ok(Matrix {
-                elements,
-                size: self.size().clone(),
-            }),
-            Err(e) => Err(e),
-        }
+    pub fn add<M: AsMatrixView<T>>(&self, right: &M) -> Result<Matrix<T>, LinearError> {
+        self.zip_with(right, |a, b| a + b)
     }
 
-    pub fn subtract(&self, right: &Matrix<T>) -> Result<Matrix<T>, &'static str> {
-        match self.add_with_coeficient(right, T::minus_one()) {
-            Ok(elements) => Ok(Matrix {
-                elements,
-                size: self.size().clone(),
-            }),
-            Err(e) => Err(e),
-        }
+    pub fn subtract<M: AsMatrixView<T>>(&self, right: &M) -> Result<Matrix<T>, LinearError>
+    where
+        T: Sub<Output = T>,
+    {
+        self.zip_with(right, |a, b| a - b)
     }
 
     pub fn scalar(&self, val: T) -> Matrix<T> {
         let mut elements: Vec<T> = vec![T::zero(); self.elements.len()];
         for i in 0..self.elements.len() {
-            elements[i] = self.elements[i] * val;
+            elements[i] = self.elements[i].clone() * val.clone();
         }
 
         Matrix {
@@ -142,17 +151,28 @@ where
             .iter()
             .skip(row * self.size.cols())
             .take(self.size.cols())
-            .into_iter()
             .collect()
     }
 
     pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if col >= self.size().cols() {
+            return None;
+        }
         self.elements.get(self.get_index(row, col))
     }
 
-    pub fn set(&mut self, row: usize, col: usize, val: T) {
+    pub fn set(&mut self, row: usize, col: usize, val: T) -> Result<(), LinearError> {
+        if row >= self.size().rows() || col >= self.size().cols() {
+            return Err(LinearError::IndexOutOfBounds {
+                row,
+                col,
+                size: self.size().clone(),
+            });
+        }
+
         let index: usize = self.get_index(row, col);
         self.elements[index] = val;
+        Ok(())
     }
 
     pub fn size(&self) -> &Size {
@@ -160,8 +180,8 @@ where
     }
 
     pub fn sum(&self) -> T {
-        self.fold(T::zero(), |mut a, &b| {
-            a += b;
+        self.fold(T::zero(), |mut a, b| {
+            a += b.clone();
             a
         })
     }
@@ -173,12 +193,68 @@ where
         self.sum() / T::from_usize(self.elements.len())
     }
 
+    pub fn max(&self) -> T {
+        max_of(self.elements.iter())
+    }
+
+    pub fn min(&self) -> T {
+        min_of(self.elements.iter())
+    }
+
     pub fn order(&self) -> usize {
         self.size().rows() * self.size().cols()
     }
 
-    fn has_same_order(&self, other: &Matrix<T>) -> bool {
-        self.order() == other.order()
+    // Gauss-Jordan elimination to reduced row echelon form.
+    // Pivots are compared with exact zero, so the result is only reliable for exact
+    // element types like Rational, for floats Matrix::rank takes a tolerance instead.
+    pub fn reduced_row_echelon(&self) -> Matrix<T>
+    where
+        T: Sub<Output = T> + Div<Output = T>,
+    {
+        let rows: usize = self.size().rows();
+        let cols: usize = self.size().cols();
+        let mut elements: Vec<T> = self.elements.clone();
+        let mut row: usize = 0;
+        for c in 0..cols {
+            if row == rows {
+                break;
+            }
+            let pivot: usize = (row..rows).fold(row, |best, r| {
+                if elements[r * cols + c].absolute() > elements[best * cols + c].absolute() {
+                    r
+                } else {
+                    best
+                }
+            });
+            if elements[pivot * cols + c] == T::zero() {
+                continue;
+            }
+            for j in 0..cols {
+                elements.swap(row * cols + j, pivot * cols + j);
+            }
+
+            let diagonal: T = elements[row * cols + c].clone();
+            for j in c..cols {
+                elements[row * cols + j] = elements[row * cols + j].clone() / diagonal.clone();
+            }
+            for r in 0..rows {
+                let factor: T = elements[r * cols + c].clone();
+                if r == row || factor == T::zero() {
+                    continue;
+                }
+                for j in c..cols {
+                    elements[r * cols + j] = elements[r * cols + j].clone()
+                        - factor.clone() * elements[row * cols + j].clone();
+                }
+            }
+            row += 1;
+        }
+
+        Matrix {
+            elements,
+            size: self.size().clone(),
+        }
     }
 
     fn to_row_major(data: &[Vec<T>]) -> Vec<T> {
@@ -189,7 +265,7 @@ where
         })
     }
 
-    fn get_size(data: &Vec<Vec<T>>) -> Size {
+    fn get_size(data: &[Vec<T>]) -> Size {
         let rows: usize = data.len();
         let cols: usize = data.first().unwrap().len();
         Size::new(rows, cols)
@@ -198,53 +274,303 @@ where
     fn get_index(&self, row: usize, col: usize) -> usize {
         row * self.size().cols() + col
     }
+}
+
+impl<T: Float> Matrix<T> {
+    pub fn norm_frobenius(&self) -> T {
+        self.view().norm_frobenius()
+    }
+
+    pub fn random_normal<R: Rng + ?Sized>(
+        rng: &mut R,
+        mean: T,
+        std_dev: T,
+        rows: usize,
+        cols: usize,
+    ) -> Result<Matrix<T>, LinearError> {
+        Ok(Matrix {
+            elements: Generator::normal(rng, mean, std_dev, rows * cols)?,
+            size: Size::new(rows, cols),
+        })
+    }
 
-    fn fold(&self, init: T, f: fn(T, &T) -> T) -> T {
-        self.elements.iter().fold(init, f)
+    // Weight initializers for a layer computing W x, so fan_in = cols and
+    // fan_out = rows. See Generator::xavier and Generator::he.
+    pub fn xavier<R: Rng + ?Sized>(
+        rng: &mut R,
+        rows: usize,
+        cols: usize,
+    ) -> Result<Matrix<T>, LinearError> {
+        Ok(Matrix {
+            elements: Generator::xavier(rng, cols, rows, rows * cols)?,
+            size: Size::new(rows, cols),
+        })
     }
 
-    fn add_with_coeficient(
-        &self,
-        right: &Matrix<T>,
-        coeficient: T,
-    ) -> Result<Vec<T>, &'static str> {
-        if !self.has_same_order(right) {
-            return Err("matrices must have the same order");
+    pub fn he<R: Rng + ?Sized>(
+        rng: &mut R,
+        rows: usize,
+        cols: usize,
+    ) -> Result<Matrix<T>, LinearError> {
+        Ok(Matrix {
+            elements: Generator::he(rng, cols, rows * cols)?,
+            size: Size::new(rows, cols),
+        })
+    }
+
+    // Orthogonal matrix distributed uniformly (Haar measure): the Q factor of a
+    // standard normal matrix, orthonormalized column by column with modified
+    // Gram-Schmidt so that R has a positive diagonal.
+    pub fn random_orthogonal<R: Rng + ?Sized>(rng: &mut R, n: usize) -> Matrix<T> {
+        let mut columns: Vec<Vec<T>> = (0..n).map(|_| Generator::standard_normal(rng, n)).collect();
+
+        for j in 0..n {
+            for k in 0..j {
+                let (done, rest) = columns.split_at_mut(j);
+                let projection: T = Self::dot(&done[k], &rest[0]);
+                for (e, d) in rest[0].iter_mut().zip(&done[k]) {
+                    *e = *e - projection * *d;
+                }
+            }
+            let norm: T = Self::dot(&columns[j], &columns[j]).sqrt();
+            for e in columns[j].iter_mut() {
+                *e = *e / norm;
+            }
         }
 
-        let mut elements: Vec<T> = vec![T::zero(); self.elements.len()];
-        for i in 0..self.elements.len() {
-            elements[i] = self.elements[i] + (coeficient * right.elements[i]);
+        Matrix {
+            elements: (0..n * n).map(|k| columns[k % n][k / n]).collect(),
+            size: Size::new(n, n),
+        }
+    }
+
+    // Symmetric positive definite Q D Q^T with a random orthogonal Q and eigenvalues
+    // spread geometrically over [1, condition], so the 2-norm condition number is
+    // exactly condition. The result is symmetric bit for bit.
+    pub fn random_spd<R: Rng + ?Sized>(
+        rng: &mut R,
+        n: usize,
+        condition: T,
+    ) -> Result<Matrix<T>, LinearError> {
+        if condition < T::one() || condition.is_nan() {
+            return Err(LinearError::InvalidArgument {
+                name: "condition",
+                message: "must be at least 1",
+            });
+        }
+        let q: Matrix<T> = Self::random_orthogonal(rng, n);
+        let eigenvalues: Vec<T> = (0..n)
+            .map(|k| match n {
+                1 => T::one(),
+                _ => condition.powf(T::from_usize(k) / T::from_usize(n - 1)),
+            })
+            .collect();
+
+        let mut elements: Vec<T> = vec![T::zero(); n * n];
+        for i in 0..n {
+            for j in i..n {
+                let mut sum: T = T::zero();
+                for (k, &eigenvalue) in eigenvalues.iter().enumerate() {
+                    sum += q.elements[i * n + k] * eigenvalue * q.elements[j * n + k];
+                }
+                elements[i * n + j] = sum;
+                elements[j * n + i] = sum;
+            }
         }
 
-        Ok(elements)
+        Ok(Matrix {
+            elements,
+            size: Size::new(n, n),
+        })
     }
-}
 
-impl Matrix<i32> {
-    pub fn max(&self) -> i32 {
-        *self.elements.iter().max().unwrap()
+    fn dot(left: &[T], right: &[T]) -> T {
+        let mut sum: T = T::zero();
+        for (&l, &r) in left.iter().zip(right.iter()) {
+            sum += l * r;
+        }
+        sum
+    }
+
+    // Zero only if elimination hits an exactly zero pivot column, a nearly singular
+    // matrix gets its tiny determinant rather than a rounded 0.
+    pub fn determinant(&self) -> Result<T, LinearError> {
+        if self.size().rows() != self.size().cols() {
+            return Err(LinearError::NotSquare {
+                size: self.size().clone(),
+            });
+        }
+
+        let n: usize = self.size().rows();
+        let mut elements: Vec<T> = self.elements.clone();
+        let mut det: T = T::one();
+        for k in 0..n {
+            let pivot: usize = Self::find_pivot(&elements, n, k, k);
+            if elements[pivot * n + k] == T::zero() {
+                return Ok(T::zero());
+            }
+            if pivot != k {
+                Self::swap_rows(&mut elements, n, k, pivot);
+                det = -det;
+            }
+            det *= elements[k * n + k];
+            Self::eliminate_below(&mut elements, n, k, k);
+        }
+
+        Ok(det)
     }
 
-    pub fn min(&self) -> i32 {
-        *self.elements.iter().min().unwrap()
+    // Gauss-Jordan elimination with partial pivoting on [A | I]. SingularMatrix if the
+    // pivot of column k is at most n * eps * max_i |a_ik|, relative to its own column
+    // so that scaling a column of A does not change the answer.
+    pub fn inverse(&self) -> Result<Matrix<T>, LinearError> {
+        if self.size().rows() != self.size().cols() {
+            return Err(LinearError::NotSquare {
+                size: self.size().clone(),
+            });
+        }
+
+        let n: usize = self.size().rows();
+        let cols: usize = 2 * n;
+        let mut elements: Vec<T> = vec![T::zero(); n * cols];
+        for r in 0..n {
+            elements[r * cols..r * cols + n].copy_from_slice(&self.elements[r * n..(r + 1) * n]);
+            elements[r * cols + n + r] = T::one();
+        }
+
+        for k in 0..n {
+            let scale: T = (0..n)
+                .map(|r| self.elements[r * n + k].absolute())
+                .fold(T::zero(), |a, e| if e > a { e } else { a });
+            let pivot: usize = Self::find_pivot(&elements, cols, k, k);
+            if elements[pivot * cols + k].absolute() <= T::from_usize(n) * T::epsilon() * scale {
+                return Err(LinearError::SingularMatrix { pivot: k });
+            }
+            Self::swap_rows(&mut elements, cols, k, pivot);
+
+            let diagonal: T = elements[k * cols + k];
+            for c in 0..cols {
+                elements[k * cols + c] = elements[k * cols + c] / diagonal;
+            }
+            for r in 0..n {
+                let factor: T = elements[r * cols + k];
+                if r == k || factor == T::zero() {
+                    continue;
+                }
+                for c in 0..cols {
+                    elements[r * cols + c] =
+                        elements[r * cols + c] - factor * elements[k * cols + c];
+                }
+            }
+        }
+
+        Ok(Matrix {
+            elements: elements
+                .chunks(cols)
+                .flat_map(|row| row[n..].to_vec())
+                .collect(),
+            size: Size::new(n, n),
+        })
     }
-}
 
-impl Matrix<f64> {
-    pub fn max(&self) -> f64 {
-        self.fold(f64::NEG_INFINITY, |a, &b| a.max(b))
+    // number of pivots larger than tolerance in the row echelon form
+    pub fn rank(&self, tolerance: T) -> usize {
+        let rows: usize = self.size().rows();
+        let cols: usize = self.size().cols();
+        let mut elements: Vec<T> = self.elements.clone();
+        let mut rank: usize = 0;
+        for c in 0..cols {
+            if rank == rows {
+                break;
+            }
+            let pivot: usize = Self::find_pivot(&elements, cols, rank, c);
+            if elements[pivot * cols + c].absolute() <= tolerance {
+                continue;
+            }
+            Self::swap_rows(&mut elements, cols, rank, pivot);
+            Self::eliminate_below(&mut elements, cols, rank, c);
+            rank += 1;
+        }
+
+        rank
     }
 
-    pub fn min(&self) -> f64 {
-        self.fold(f64::INFINITY, |a, &b| a.min(b))
+    // row with the largest absolute value in column col, starting from row
+    fn find_pivot(elements: &[T], cols: usize, row: usize, col: usize) -> usize {
+        let rows: usize = elements.len() / cols;
+        let mut pivot: usize = row;
+        let mut max: T = elements[row * cols + col].absolute();
+        for r in (row + 1)..rows {
+            let abs: T = elements[r * cols + col].absolute();
+            if abs > max {
+                pivot = r;
+                max = abs;
+            }
+        }
+        pivot
+    }
+
+    fn swap_rows(elements: &mut [T], cols: usize, i: usize, j: usize) {
+        for c in 0..cols {
+            elements.swap(i * cols + c, j * cols + c);
+        }
+    }
+
+    fn eliminate_below(elements: &mut [T], cols: usize, row: usize, col: usize) {
+        let rows: usize = elements.len() / cols;
+        for r in (row + 1)..rows {
+            let factor: T = elements[r * cols + col] / elements[row * cols + col];
+            for c in col..cols {
+                elements[r * cols + c] = elements[r * cols + c] - factor * elements[row * cols + c];
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Matrix<T>
+where
+    T: serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "Matrix")]
+        struct Fields<T> {
+            elements: Vec<T>,
+            size: Size,
+        }
+
+        let fields: Fields<T> = Fields::deserialize(deserializer)?;
+        if fields.size.rows().checked_mul(fields.size.cols()) != Some(fields.elements.len()) {
+            return Err(serde::de::Error::custom(format!(
+                "a {}x{} matrix needs {} elements, got {}",
+                fields.size.rows(),
+                fields.size.cols(),
+                fields.size.rows().saturating_mul(fields.size.cols()),
+                fields.elements.len()
+            )));
+        }
+
+        Ok(Matrix {
+            elements: fields.elements,
+            size: fields.size,
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
 
-    use crate::vector::{shape::Shape, vector::Vector};
+    use rand::rngs::StdRng;
+
+    use crate::{
+        complex::Complex,
+        error::LinearError,
+        generator::Generator,
+        matrix::size::Size,
+        rational::Rational,
+        vector::{shape::Shape, vector::Vector},
+    };
 
     use super::Matrix;
 
@@ -285,6 +611,57 @@ mod test {
         assert!(matrix.max() <= high);
     }
 
+    #[test]
+    fn test_random_seeded() {
+        let first: Matrix<f64> = Matrix::random_with(&mut Generator::seeded(9), -1.0, 1.0, 4, 3);
+        let second: Matrix<f64> = Matrix::random_with(&mut Generator::seeded(9), -1.0, 1.0, 4, 3);
+        assert!(first.equals(&second));
+
+        let mut rng: StdRng = Generator::seeded(10);
+        let mask: Matrix<i32> = Matrix::random_bernoulli(&mut rng, 0.5, 10, 10).unwrap();
+        assert!(mask.elements.iter().all(|&x| x == 0 || x == 1));
+
+        let weights: Matrix<f32> = Matrix::he(&mut rng, 8, 32).unwrap();
+        assert_eq!(*weights.size(), Size::new(8, 32));
+        let weights: Matrix<f32> = Matrix::xavier(&mut rng, 8, 32).unwrap();
+        assert!(weights.max() < 0.39 && weights.min() > -0.39);
+    }
+
+    #[test]
+    fn test_random_orthogonal() {
+        let q: Matrix<f64> = Matrix::random_orthogonal(&mut Generator::seeded(11), 6);
+        let identity: Matrix<f64> = q.transpose().product(&q).unwrap();
+
+        for i in 0..6 {
+            for j in 0..6 {
+                let expected: f64 = if i == j { 1.0 } else { 0.0 };
+                assert!((identity[(i, j)] - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_spd() {
+        let a: Matrix<f64> = Matrix::random_spd(&mut Generator::seeded(12), 5, 100.0).unwrap();
+
+        assert!(a.equals(&a.transpose()));
+        // the eigenvalues lie in [1, 100], so does the Rayleigh quotient
+        let x: Vector<f64> =
+            Vector::random_normal(&mut Generator::seeded(13), 0.0, 1.0, 5, Shape::Col).unwrap();
+        let ax: Vector<f64> = a.vector_product(&x).unwrap();
+        let energy: f64 = x.dot(&ax).unwrap();
+        assert!(energy >= x.dot(&x).unwrap() - 1e-9);
+        assert!(energy <= 100.0 * x.dot(&x).unwrap() + 1e-9);
+
+        assert_eq!(
+            Matrix::<f64>::random_spd(&mut Generator::seeded(12), 5, 0.5).err(),
+            Some(LinearError::InvalidArgument {
+                name: "condition",
+                message: "must be at least 1"
+            })
+        );
+    }
+
     #[test]
     fn test_get() {
         let matrix: Matrix<f64> = get_default_matrix();
@@ -298,25 +675,54 @@ mod test {
     fn test_set() {
         let mut matrix: Matrix<f64> = get_default_matrix();
 
-        matrix.set(0, 0, 999.999);
-        matrix.set(1, 2, 777.999);
-        matrix.set(2, 1, 1.0);
+        matrix.set(0, 0, 999.999).unwrap();
+        matrix.set(1, 2, 777.999).unwrap();
+        matrix.set(2, 1, 1.0).unwrap();
 
         assert_eq!(*matrix.get(0, 0).unwrap(), 999.999);
         assert_eq!(*matrix.get(1, 2).unwrap(), 777.999);
         assert_eq!(*matrix.get(2, 1).unwrap(), 1.0);
     }
 
+    #[test]
+    fn test_get_out_of_bounds() {
+        let mut matrix: Matrix<f64> = get_default_matrix();
+
+        assert_eq!(matrix.get(0, 3), None);
+        assert_eq!(matrix.get(3, 0), None);
+        assert_eq!(
+            matrix.set(1, 3, 1.0),
+            Err(LinearError::IndexOutOfBounds {
+                row: 1,
+                col: 3,
+                size: Size::new(3, 3)
+            })
+        );
+    }
+
     #[test]
     fn test_sum() {
         let matrix: Matrix<f64> = get_default_matrix();
-        assert!(matrix.sum() - 40.6 < f64::EPSILON);
+        assert!((matrix.sum() - 40.6).abs() < 1e-10);
     }
 
     #[test]
     fn test_mean() {
         let matrix: Matrix<f64> = get_default_matrix();
-        assert!(matrix.mean() - (40.6 / 9.0) < f64::EPSILON);
+        assert!((matrix.mean() - 40.6 / 9.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_max_min() {
+        let matrix: Matrix<f64> = get_default_matrix();
+        let unsigned: Matrix<u8> = Matrix::new(&vec![vec![3, 250], vec![0, 7]]);
+        let single: Matrix<f32> = Matrix::new(&vec![vec![-1.5, 2.5]]);
+
+        assert_eq!(matrix.max(), 7.5);
+        assert_eq!(matrix.min(), 1.0);
+        assert_eq!((unsigned.max(), unsigned.min()), (250, 0));
+        assert_eq!((single.max(), single.min()), (2.5, -1.5));
+        assert_eq!(single.mean(), 0.5);
     }
 
     #[test]
@@ -332,6 +738,44 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_conjugate_transpose() {
+        let matrix: Matrix<Complex<f64>> = Matrix::new(&vec![
+            vec![
+                Complex::new(1.0, 2.0),
+                Complex::new(0.0, -1.0),
+                Complex::new(3.0, 0.0),
+            ],
+            vec![
+                Complex::new(4.0, 0.0),
+                Complex::new(5.0, 5.0),
+                Complex::new(0.0, 1.0),
+            ],
+        ]);
+        let expected: Matrix<Complex<f64>> = Matrix::new(&vec![
+            vec![Complex::new(1.0, -2.0), Complex::new(4.0, 0.0)],
+            vec![Complex::new(0.0, 1.0), Complex::new(5.0, -5.0)],
+            vec![Complex::new(3.0, 0.0), Complex::new(0.0, -1.0)],
+        ]);
+
+        assert!(matrix.conjugate_transpose().equals(&expected));
+        assert_eq!(matrix.conjugate_transpose().size(), &Size::new(3, 2));
+        assert!(get_default_matrix()
+            .conjugate_transpose()
+            .equals(&get_default_matrix_transposed()));
+    }
+
+    #[test]
+    fn test_transpose_rectangular() {
+        let matrix: Matrix<i32> = Matrix::new(&vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let tm: Matrix<i32> = matrix.transpose();
+        let expected: Matrix<i32> = Matrix::new(&vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+
+        assert_eq!(tm.size().rows(), 3);
+        assert_eq!(tm.size().cols(), 2);
+        assert!(tm.equals(&expected));
+    }
+
     #[test]
     fn test_equals() {
         let matrix1: Matrix<f64> = get_default_matrix();
@@ -353,7 +797,7 @@ mod test {
     fn test_add() {
         let left: Matrix<f64> = get_default_matrix();
         let right: Matrix<f64> = get_default_matrix();
-        let result: Result<Matrix<f64>, &str> = left.add(&right);
+        let result: Result<Matrix<f64>, LinearError> = left.add(&right);
         let expected: Matrix<f64> = Matrix::new(&vec![
             vec![2.4, 4.8, 7.0],
             vec![9.4, 12.2, 14.4],
@@ -366,11 +810,44 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_add_dimension_mismatch() {
+        let left: Matrix<i32> = Matrix::new(&vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let right: Matrix<i32> = left.transpose();
+
+        assert_eq!(
+            left.add(&right).err(),
+            Some(LinearError::DimensionMismatch {
+                left: Size::new(2, 3),
+                right: Size::new(3, 2)
+            })
+        );
+        assert!(left.product(&left).is_err());
+    }
+
+    #[test]
+    fn test_add_views() {
+        let matrix: Matrix<i32> = Matrix::new(&vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let left: Matrix<i32> = matrix.slice(0..2, 0..2).to_matrix();
+
+        assert!(left
+            .add(&matrix.slice(0..2, 1..3))
+            .unwrap()
+            .equals(&Matrix::new(&vec![vec![3, 5], vec![9, 11]])));
+        assert!(left
+            .subtract(&matrix.transposed().slice(0..2, 0..2))
+            .unwrap()
+            .equals(&Matrix::new(&vec![vec![0, -2], vec![2, 0]])));
+        assert!(left
+            .hadamard_product(&Vector::new(vec![1, 2], Shape::Row))
+            .is_err());
+    }
+
     #[test]
     fn test_subtraction() {
         let left: Matrix<f64> = get_default_matrix();
         let right: Matrix<f64> = get_default_matrix();
-        let result: Result<Matrix<f64>, &str> = left.subtract(&right);
+        let result: Result<Matrix<f64>, LinearError> = left.subtract(&right);
         let expected: Matrix<f64> = Matrix::new(&vec![
             vec![0.0, 0.0, 0.0],
             vec![0.0, 0.0, 0.0],
@@ -402,7 +879,7 @@ mod test {
     fn test_vector_product() {
         let matrix: Matrix<i32> = Matrix::new(&vec![vec![1, -1, 2], vec![0, -3, 1]]);
         let vector: Vector<i32> = Vector::new(vec![2, 1, 0], Shape::Row);
-        let product: Result<Vector<i32>, &str> = matrix.vector_product(&vector);
+        let product: Result<Vector<i32>, LinearError> = matrix.vector_product(&vector);
         let expected: Vector<i32> = Vector::new(vec![1, -3], Shape::Col);
 
         match product {
@@ -411,6 +888,158 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_determinant() {
+        let matrix: Matrix<f64> = get_default_matrix();
+        assert!((matrix.determinant().unwrap() - -50.38).abs() < 1e-10);
+
+        let swapped: Matrix<f64> = Matrix::new(&vec![vec![0.0, 2.0], vec![3.0, 0.0]]);
+        assert_eq!(swapped.determinant().unwrap(), -6.0);
+
+        let rectangular: Matrix<f64> = Matrix::new(&vec![vec![1.0, 2.0, 3.0]]);
+        assert_eq!(
+            rectangular.determinant(),
+            Err(LinearError::NotSquare {
+                size: Size::new(1, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        let matrix: Matrix<f64> = get_default_matrix();
+        let inverse: Matrix<f64> = matrix.inverse().unwrap();
+        let identity: Matrix<f64> = matrix.product(&inverse).unwrap();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected: f64 = if i == j { 1.0 } else { 0.0 };
+                assert!((identity.get(i, j).unwrap() - expected).abs() < 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        let matrix: Matrix<f64> = Matrix::new(&vec![
+            vec![1.0, 2.0, 3.0],
+            vec![2.0, 4.0, 6.0],
+            vec![1.0, 0.0, 1.0],
+        ]);
+
+        assert_eq!(
+            matrix.inverse().err(),
+            Some(LinearError::SingularMatrix { pivot: 2 })
+        );
+        assert_eq!(matrix.determinant().unwrap(), 0.0);
+
+        // singular up to rounding, elimination leaves a pivot of about 1e-16
+        let nearly: Matrix<f64> = Matrix::new(&vec![
+            vec![0.1, 0.2, 0.3],
+            vec![0.4, 0.5, 0.6],
+            vec![0.7, 0.8, 0.9],
+        ]);
+        assert!(nearly.inverse().is_err());
+        assert!(nearly.determinant().unwrap().abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_inverse_badly_scaled() {
+        let matrix: Matrix<f64> = Matrix::new(&vec![vec![1e-10, 0.0], vec![0.0, 1e10]]);
+        assert!((matrix.determinant().unwrap() - 1.0).abs() < 1e-12);
+
+        let inverse: Matrix<f64> = matrix.inverse().unwrap();
+        assert!((inverse.get(0, 0).unwrap() - 1e10).abs() < 1e-2);
+        assert!((inverse.get(1, 1).unwrap() - 1e-10).abs() < 1e-22);
+        assert_eq!(*inverse.get(0, 1).unwrap(), 0.0);
+        assert_eq!(*inverse.get(1, 0).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_rank() {
+        let matrix: Matrix<f64> = Matrix::new(&vec![
+            vec![1.0, 2.0, 3.0, 4.0],
+            vec![2.0, 4.0, 6.0, 8.0],
+            vec![1.0, 0.0, 1.0, 0.0],
+        ]);
+
+        assert_eq!(matrix.rank(1e-10), 2);
+        assert_eq!(get_default_matrix().rank(1e-10), 3);
+        assert_eq!(matrix.transpose().rank(1e-10), 2);
+    }
+
+    #[test]
+    fn test_f32() {
+        let matrix: Matrix<f32> = Matrix::new(&vec![vec![4.0, 7.0], vec![2.0, 6.0]]);
+        assert!((matrix.determinant().unwrap() - 10.0).abs() < 1e-5);
+        assert_eq!(matrix.rank(1e-5), 2);
+
+        let inverse: Matrix<f32> = matrix.inverse().unwrap();
+        let identity: Matrix<f32> = matrix.product(&inverse).unwrap();
+        for i in 0..2 {
+            for j in 0..2 {
+                let expected: f32 = if i == j { 1.0 } else { 0.0 };
+                assert!((identity[(i, j)] - expected).abs() < 1e-5);
+            }
+        }
+
+        let singular: Matrix<f32> = Matrix::new(&vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+        assert!(singular.inverse().is_err());
+    }
+
+    #[test]
+    fn test_reduced_row_echelon() {
+        // third row is the sum of the first two
+        let r = |n: i128, d: i128| Rational::new(n, d);
+        let matrix: Matrix<Rational> = Matrix::new(&vec![
+            vec![r(1, 1), r(2, 1), r(1, 3), r(1, 1)],
+            vec![r(2, 1), r(1, 2), r(0, 1), r(3, 1)],
+            vec![r(3, 1), r(5, 2), r(1, 3), r(4, 1)],
+        ]);
+        let expected: Matrix<Rational> = Matrix::new(&vec![
+            vec![r(1, 1), r(0, 1), r(-1, 21), r(11, 7)],
+            vec![r(0, 1), r(1, 1), r(4, 21), r(-2, 7)],
+            vec![r(0, 1), r(0, 1), r(0, 1), r(0, 1)],
+        ]);
+
+        assert!(matrix.reduced_row_echelon().equals(&expected));
+
+        // the intermediate fractions of a 30 x 30 Hilbert matrix do not fit in 128 bits
+        let hilbert: Matrix<Rational> = Matrix::new(
+            &(1..=30)
+                .map(|i| (1..=30).map(|j| Rational::new(1, i + j - 1)).collect())
+                .collect(),
+        );
+        let identity: Matrix<Rational> = Matrix::new(
+            &(0..30)
+                .map(|i| (0..30).map(|j| Rational::from(i32::from(i == j))).collect())
+                .collect(),
+        );
+        assert!(hilbert.reduced_row_echelon().equals(&identity));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let matrix: Matrix<f64> = get_default_matrix();
+        let json: String = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(
+            json,
+            r#"{"elements":[1.2,2.4,3.5,4.7,6.1,7.2,7.0,1.0,7.5],"size":{"rows":3,"cols":3}}"#
+        );
+        assert!(serde_json::from_str::<Matrix<f64>>(&json)
+            .unwrap()
+            .equals(&matrix));
+
+        let error: String = serde_json::from_str::<Matrix<i32>>(
+            r#"{"elements":[1,2,3],"size":{"rows":2,"cols":2}}"#,
+        )
+        .err()
+        .unwrap()
+        .to_string();
+        assert!(error.starts_with("a 2x2 matrix needs 4 elements, got 3"));
+    }
+
     fn get_default_matrix() -> Matrix<f64> {
         let elements: Vec<Vec<f64>> = vec![
             vec![1.2, 2.4, 3.5],
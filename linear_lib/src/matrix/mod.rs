@@ -1,2 +1,8 @@
+mod constructors;
+mod functional;
+#[allow(clippy::module_inception)]
 pub mod matrix;
+mod multiply;
+pub mod ops;
 pub mod size;
+pub mod view;
@@ -0,0 +1,89 @@
+use std::ops::{Add, Mul};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::num::Num;
+
+// Edge of the square tiles the product is split into. A 64x64 tile of f64 is 32KB,
+// so a tile of b stays in L1/L2 while a block of rows of the result is updated.
+const BLOCK: usize = 64;
+
+// c = a * b for row-major a (m x k) and b (k x n).
+// The result is split into blocks of BLOCK rows which are independent of each other,
+// with the `parallel` feature they are computed on the rayon thread pool.
+pub(crate) fn multiply<T>(a: &[T], b: &[T], m: usize, k: usize, n: usize) -> Vec<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    let mut c: Vec<T> = vec![T::zero(); m * n];
+    if m == 0 || n == 0 || k == 0 {
+        return c;
+    }
+
+    #[cfg(feature = "parallel")]
+    c.par_chunks_mut(BLOCK * n)
+        .zip(a.par_chunks(BLOCK * k))
+        .for_each(|(c_rows, a_rows)| multiply_rows(a_rows, b, c_rows, k, n));
+
+    #[cfg(not(feature = "parallel"))]
+    c.chunks_mut(BLOCK * n)
+        .zip(a.chunks(BLOCK * k))
+        .for_each(|(c_rows, a_rows)| multiply_rows(a_rows, b, c_rows, k, n));
+
+    c
+}
+
+// c_rows += a_rows * b, walking b tile by tile. The inner loop runs over a contiguous
+// row of b and of c, which the compiler can vectorize.
+fn multiply_rows<T>(a_rows: &[T], b: &[T], c_rows: &mut [T], k: usize, n: usize)
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    let rows: usize = c_rows.len() / n;
+
+    for p_start in (0..k).step_by(BLOCK) {
+        let p_end: usize = (p_start + BLOCK).min(k);
+        for j_start in (0..n).step_by(BLOCK) {
+            let j_end: usize = (j_start + BLOCK).min(n);
+            for i in 0..rows {
+                let c_row: &mut [T] = &mut c_rows[i * n + j_start..i * n + j_end];
+                for p in p_start..p_end {
+                    let a_ip: &T = &a_rows[i * k + p];
+                    let b_row: &[T] = &b[p * n + j_start..p * n + j_end];
+                    for (c, b) in c_row.iter_mut().zip(b_row) {
+                        *c += a_ip.clone() * b.clone();
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::matrix::matrix::Matrix;
+
+    use super::multiply;
+
+    #[test]
+    fn test_multiply_uneven_blocks() {
+        let (m, k, n): (usize, usize, usize) = (70, 130, 65);
+        let a: Matrix<i32> = Matrix::random(-10, 10, m, k);
+        let b: Matrix<i32> = Matrix::random(-10, 10, k, n);
+        let c: Vec<i32> = multiply(&a.elements, &b.elements, m, k, n);
+
+        for i in 0..m {
+            for j in 0..n {
+                let expected: i32 = (0..k).map(|p| a[(i, p)] * b[(p, j)]).sum();
+                assert_eq!(c[i * n + j], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiply_empty() {
+        assert_eq!(multiply::<f64>(&[], &[], 3, 0, 2), vec![0.0; 6]);
+        assert!(multiply::<f64>(&[], &[1.0, 2.0], 0, 1, 2).is_empty());
+    }
+}
@@ -0,0 +1,324 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{error::LinearError, num::Num, vector::vector::Vector};
+
+use super::matrix::Matrix;
+
+// Operators panic on incompatible sizes, use the Result returning methods
+// (add, subtract, product, vector_product) to handle that case.
+
+// implements the owned variants of a binary operator by borrowing both sides
+macro_rules! forward_ref_binop {
+    ($imp:ident, $method:ident, $rhs:ident, $output:ident) => {
+        impl<T> $imp<$rhs<T>> for Matrix<T>
+        where
+            T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+        {
+            type Output = $output<T>;
+
+            fn $method(self, rhs: $rhs<T>) -> Self::Output {
+                $imp::$method(&self, &rhs)
+            }
+        }
+
+        impl<T> $imp<&$rhs<T>> for Matrix<T>
+        where
+            T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+        {
+            type Output = $output<T>;
+
+            fn $method(self, rhs: &$rhs<T>) -> Self::Output {
+                $imp::$method(&self, rhs)
+            }
+        }
+
+        impl<T> $imp<$rhs<T>> for &Matrix<T>
+        where
+            T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+        {
+            type Output = $output<T>;
+
+            fn $method(self, rhs: $rhs<T>) -> Self::Output {
+                $imp::$method(self, &rhs)
+            }
+        }
+    };
+}
+
+impl<T> Add<&Matrix<T>> for &Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: &Matrix<T>) -> Self::Output {
+        Matrix::add(self, rhs).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl<T> Sub<&Matrix<T>> for &Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: &Matrix<T>) -> Self::Output {
+        self.subtract(rhs).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl<T> Mul<&Matrix<T>> for &Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        self.product(rhs).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl<T> Mul<&Vector<T>> for &Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        self.vector_product(rhs).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+forward_ref_binop!(Add, add, Matrix, Matrix);
+forward_ref_binop!(Sub, sub, Matrix, Matrix);
+forward_ref_binop!(Mul, mul, Matrix, Matrix);
+forward_ref_binop!(Mul, mul, Vector, Vector);
+
+impl<T> Mul<T> for &Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.scalar(rhs)
+    }
+}
+
+impl<T> Mul<T> for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    type Output = Matrix<T>;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl<T> Neg for &Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Neg<Output = T> + Num,
+{
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+impl<T> Neg for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Neg<Output = T> + Num,
+{
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Self::Output {
+        self.elements.iter_mut().for_each(|e| *e = -e.clone());
+        self
+    }
+}
+
+impl<T> AddAssign<&Matrix<T>> for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        Self::assert_same_size(self, rhs);
+        for (e, r) in self.elements.iter_mut().zip(rhs.elements.iter()) {
+            *e += r.clone();
+        }
+    }
+}
+
+impl<T> AddAssign<Matrix<T>> for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    fn add_assign(&mut self, rhs: Matrix<T>) {
+        *self += &rhs;
+    }
+}
+
+impl<T> SubAssign<&Matrix<T>> for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        Self::assert_same_size(self, rhs);
+        for (e, r) in self.elements.iter_mut().zip(rhs.elements.iter()) {
+            *e = e.clone() - r.clone();
+        }
+    }
+}
+
+impl<T> SubAssign<Matrix<T>> for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    fn sub_assign(&mut self, rhs: Matrix<T>) {
+        *self -= &rhs;
+    }
+}
+
+impl<T> MulAssign<&Matrix<T>> for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    fn mul_assign(&mut self, rhs: &Matrix<T>) {
+        *self = &*self * rhs;
+    }
+}
+
+impl<T> MulAssign<Matrix<T>> for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    fn mul_assign(&mut self, rhs: Matrix<T>) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl<T> MulAssign<T> for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        self.elements.iter_mut().for_each(|e| *e *= rhs.clone());
+    }
+}
+
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        Self::assert_in_bounds(self, row, col);
+        &self.elements[row * self.size.cols() + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        Self::assert_in_bounds(self, row, col);
+        let cols: usize = self.size.cols();
+        &mut self.elements[row * cols + col]
+    }
+}
+
+impl<T> Matrix<T> {
+    fn assert_in_bounds(&self, row: usize, col: usize) {
+        if row >= self.size.rows() || col >= self.size.cols() {
+            panic!(
+                "{}",
+                LinearError::IndexOutOfBounds {
+                    row,
+                    col,
+                    size: self.size.clone(),
+                }
+            );
+        }
+    }
+
+    fn assert_same_size(&self, other: &Matrix<T>) {
+        if self.size != other.size {
+            panic!(
+                "{}",
+                LinearError::DimensionMismatch {
+                    left: self.size.clone(),
+                    right: other.size.clone(),
+                }
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        matrix::matrix::Matrix,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    #[test]
+    fn test_add_sub() {
+        let left: Matrix<i32> = Matrix::new(&vec![vec![1, 2], vec![3, 4]]);
+        let right: Matrix<i32> = Matrix::new(&vec![vec![5, 6], vec![7, 8]]);
+
+        assert!((&left + &right).equals(&Matrix::new(&vec![vec![6, 8], vec![10, 12]])));
+        assert!((&right - &left).equals(&Matrix::new(&vec![vec![4, 4], vec![4, 4]])));
+        assert!((-&left).equals(&Matrix::new(&vec![vec![-1, -2], vec![-3, -4]])));
+
+        let mut sum: Matrix<i32> = left.clone() + right.clone();
+        sum -= &right;
+        assert!(sum.equals(&left));
+        sum += right;
+        assert!(sum.equals(&Matrix::new(&vec![vec![6, 8], vec![10, 12]])));
+    }
+
+    #[test]
+    fn test_mul() {
+        let left: Matrix<i32> = Matrix::new(&vec![vec![1, 5], vec![2, 3], vec![1, 7]]);
+        let right: Matrix<i32> = Matrix::new(&vec![vec![1, 2, 3, 7], vec![5, 2, 8, 1]]);
+        let expected: Matrix<i32> = left.product(&right).unwrap();
+
+        assert!((&left * &right).equals(&expected));
+        assert!((&left * 2).equals(&left.scalar(2)));
+
+        let mut product: Matrix<i32> = left.clone();
+        product *= right;
+        assert!(product.equals(&expected));
+        assert_eq!(product.size().cols(), 4);
+
+        product *= 3;
+        assert!(product.equals(&expected.scalar(3)));
+
+        let vector: Vector<i32> = Vector::new(vec![2, 1], Shape::Col);
+        let expected: Vector<i32> = Vector::new(vec![7, 7, 9], Shape::Col);
+        assert!((&left * &vector).equals(&expected));
+        assert!((left * vector).equals(&expected));
+    }
+
+    #[test]
+    fn test_index() {
+        let mut matrix: Matrix<f64> = Matrix::new(&vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+
+        assert_eq!(matrix[(1, 2)], 6.0);
+        matrix[(0, 1)] = 7.0;
+        assert_eq!(*matrix.get(0, 1).unwrap(), 7.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_index_out_of_bounds() {
+        let matrix: Matrix<f64> = Matrix::new(&vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        let _ = matrix[(0, 3)];
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn test_add_dimension_mismatch() {
+        let left: Matrix<i32> = Matrix::new(&vec![vec![1, 2, 3]]);
+        let _ = &left + &left.transpose();
+    }
+}
@@ -1,3 +1,5 @@
+#[derive(PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Size {
     rows: usize,
     cols: usize,
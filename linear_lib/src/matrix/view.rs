@@ -0,0 +1,591 @@
+use std::ops::{Add, Bound, Index, IndexMut, Mul, RangeBounds};
+
+use crate::{
+    error::LinearError,
+    num::{Float, Num},
+    vector::{shape::Shape, vector::Vector},
+};
+
+use super::{matrix::Matrix, multiply::multiply, size::Size};
+
+// Anything that can be read as a matrix without copying: owned matrices and views.
+pub trait AsMatrixView<T> {
+    fn as_view(&self) -> MatrixView<'_, T>;
+}
+
+// Borrowed, possibly strided, window into the storage of a matrix.
+// Element (r, c) lives at data[offset + r * row_stride + c * col_stride].
+pub struct MatrixView<'a, T> {
+    data: &'a [T],
+    offset: usize,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+pub struct MatrixViewMut<'a, T> {
+    data: &'a mut [T],
+    offset: usize,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+}
+
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+impl<'a, T> MatrixView<'a, T> {
+    pub(crate) fn new(data: &'a [T], size: &Size) -> Self {
+        MatrixView {
+            data,
+            offset: 0,
+            rows: size.rows(),
+            cols: size.cols(),
+            row_stride: size.cols(),
+            col_stride: 1,
+        }
+    }
+
+    pub fn size(&self) -> Size {
+        Size::new(self.rows, self.cols)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        Some(&self.data[self.offset + row * self.row_stride + col * self.col_stride])
+    }
+
+    pub fn row(&self, row: usize) -> MatrixView<'a, T> {
+        self.slice(row..row + 1, ..)
+    }
+
+    pub fn col(&self, col: usize) -> MatrixView<'a, T> {
+        self.slice(.., col..col + 1)
+    }
+
+    pub fn slice<R, C>(&self, rows: R, cols: C) -> MatrixView<'a, T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (row_start, row_end) = resolve_range(rows, self.rows);
+        let (col_start, col_end) = resolve_range(cols, self.cols);
+
+        MatrixView {
+            data: self.data,
+            offset: self.offset + row_start * self.row_stride + col_start * self.col_stride,
+            rows: row_end - row_start,
+            cols: col_end - col_start,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        }
+    }
+
+    // every row_step-th row and col_step-th column, starting from the first
+    pub fn step_by(&self, row_step: usize, col_step: usize) -> MatrixView<'a, T> {
+        assert!(row_step > 0 && col_step > 0, "step must be positive");
+
+        MatrixView {
+            data: self.data,
+            offset: self.offset,
+            rows: self.rows.div_ceil(row_step),
+            cols: self.cols.div_ceil(col_step),
+            row_stride: self.row_stride * row_step,
+            col_stride: self.col_stride * col_step,
+        }
+    }
+
+    pub fn transpose(&self) -> MatrixView<'a, T> {
+        MatrixView {
+            data: self.data,
+            offset: self.offset,
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        }
+    }
+
+    // the viewed elements as one row-major slice, if they are laid out that way
+    fn as_contiguous(&self) -> Option<&'a [T]> {
+        let row_major: bool = self.rows <= 1 || self.row_stride == self.cols;
+        if (self.col_stride == 1 && row_major) || self.rows * self.cols == 0 {
+            return Some(&self.data[self.offset..self.offset + self.rows * self.cols]);
+        }
+        None
+    }
+
+    // elements in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let view: MatrixView<'a, T> = *self;
+        (0..view.rows).flat_map(move |r| {
+            (0..view.cols)
+                .map(move |c| &view.data[view.offset + r * view.row_stride + c * view.col_stride])
+        })
+    }
+}
+
+impl<T> MatrixView<'_, T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix {
+            elements: self.iter().cloned().collect(),
+            size: self.size(),
+        }
+    }
+
+    pub fn product<M: AsMatrixView<T>>(&self, right: &M) -> Result<Matrix<T>, LinearError> {
+        let right: MatrixView<'_, T> = right.as_view();
+        if self.cols != right.rows {
+            return Err(LinearError::DimensionMismatch {
+                left: self.size(),
+                right: right.size(),
+            });
+        }
+
+        // strided views are packed first so the kernel always sees row-major buffers
+        let left_packed: Vec<T>;
+        let left_elements: &[T] = match self.as_contiguous() {
+            Some(elements) => elements,
+            None => {
+                left_packed = self.iter().cloned().collect();
+                &left_packed
+            }
+        };
+        let right_packed: Vec<T>;
+        let right_elements: &[T] = match right.as_contiguous() {
+            Some(elements) => elements,
+            None => {
+                right_packed = right.iter().cloned().collect();
+                &right_packed
+            }
+        };
+
+        let elements: Vec<T> = multiply(
+            left_elements,
+            right_elements,
+            self.rows,
+            self.cols,
+            right.cols,
+        );
+
+        Ok(Matrix {
+            elements,
+            size: Size::new(self.rows, right.cols),
+        })
+    }
+
+    pub fn vector_product(&self, vector: &Vector<T>) -> Result<Vector<T>, LinearError> {
+        if self.cols != vector.size() {
+            return Err(LinearError::DimensionMismatch {
+                left: self.size(),
+                right: vector.dimensions(),
+            });
+        }
+
+        let mut elements: Vec<T> = vec![T::zero(); self.rows];
+        for (r, element) in elements.iter_mut().enumerate() {
+            for c in 0..self.cols {
+                *element += self[(r, c)].clone() * vector.elements[c].clone();
+            }
+        }
+
+        Ok(Vector::new(elements, Shape::Col))
+    }
+
+    pub fn sum(&self) -> T {
+        self.iter().fold(T::zero(), |mut a, b| {
+            a += b.clone();
+            a
+        })
+    }
+
+    // maximum absolute column sum
+    pub fn norm_one(&self) -> T {
+        self.transpose().norm_inf()
+    }
+
+    // maximum absolute row sum
+    pub fn norm_inf(&self) -> T {
+        (0..self.rows)
+            .map(|r| {
+                self.row(r).iter().fold(T::zero(), |mut a, b| {
+                    a += b.absolute();
+                    a
+                })
+            })
+            .fold(T::zero(), |a, b| if b > a { b } else { a })
+    }
+}
+
+impl<T: Float> MatrixView<'_, T> {
+    pub fn norm_frobenius(&self) -> T {
+        self.iter()
+            .fold(T::zero(), |mut a, &b| {
+                a += b * b;
+                a
+            })
+            .sqrt()
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    pub(crate) fn new(data: &'a mut [T], size: &Size) -> Self {
+        MatrixViewMut {
+            data,
+            offset: 0,
+            rows: size.rows(),
+            cols: size.cols(),
+            row_stride: size.cols(),
+            col_stride: 1,
+        }
+    }
+
+    pub fn size(&self) -> Size {
+        Size::new(self.rows, self.cols)
+    }
+
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data: self.data,
+            offset: self.offset,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        Some(&self.data[self.offset + row * self.row_stride + col * self.col_stride])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        Some(&mut self.data[self.offset + row * self.row_stride + col * self.col_stride])
+    }
+
+    pub fn slice_mut<R, C>(&mut self, rows: R, cols: C) -> MatrixViewMut<'_, T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (row_start, row_end) = resolve_range(rows, self.rows);
+        let (col_start, col_end) = resolve_range(cols, self.cols);
+
+        MatrixViewMut {
+            data: self.data,
+            offset: self.offset + row_start * self.row_stride + col_start * self.col_stride,
+            rows: row_end - row_start,
+            cols: col_end - col_start,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+        }
+    }
+
+    pub fn transpose(self) -> MatrixViewMut<'a, T> {
+        MatrixViewMut {
+            data: self.data,
+            offset: self.offset,
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+        }
+    }
+}
+
+impl<T> MatrixViewMut<'_, T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    pub fn fill(&mut self, val: T) {
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                self[(r, c)] = val.clone();
+            }
+        }
+    }
+
+    // copies the elements of source into the viewed block
+    pub fn assign<M: AsMatrixView<T>>(&mut self, source: &M) -> Result<(), LinearError> {
+        let source: MatrixView<'_, T> = source.as_view();
+        if source.size() != self.size() {
+            return Err(LinearError::DimensionMismatch {
+                left: self.size(),
+                right: source.size(),
+            });
+        }
+
+        for r in 0..self.rows {
+            for c in 0..self.cols {
+                self[(r, c)] = source[(r, c)].clone();
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("{}", out_of_bounds(row, col, self.size())))
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        let size: Size = self.size();
+        self.get(row, col)
+            .unwrap_or_else(|| panic!("{}", out_of_bounds(row, col, size)))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        let size: Size = self.size();
+        self.get_mut(row, col)
+            .unwrap_or_else(|| panic!("{}", out_of_bounds(row, col, size)))
+    }
+}
+
+impl<T> AsMatrixView<T> for Matrix<T> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView::new(&self.elements, &self.size)
+    }
+}
+
+impl<T> AsMatrixView<T> for MatrixView<'_, T> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        *self
+    }
+}
+
+impl<T> AsMatrixView<T> for MatrixViewMut<'_, T> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        self.view()
+    }
+}
+
+// 1 x n for row vectors, n x 1 for column vectors
+impl<T> AsMatrixView<T> for Vector<T> {
+    fn as_view(&self) -> MatrixView<'_, T> {
+        let size: Size = match self.shape {
+            Shape::Row => Size::new(1, self.elements.len()),
+            Shape::Col => Size::new(self.elements.len(), 1),
+        };
+        MatrixView::new(&self.elements, &size)
+    }
+}
+
+impl<T> Matrix<T> {
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView::new(&self.elements, &self.size)
+    }
+
+    pub fn view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut::new(&mut self.elements, &self.size)
+    }
+
+    pub fn row(&self, row: usize) -> MatrixView<'_, T> {
+        self.view().row(row)
+    }
+
+    pub fn col(&self, col: usize) -> MatrixView<'_, T> {
+        self.view().col(col)
+    }
+
+    pub fn slice<R, C>(&self, rows: R, cols: C) -> MatrixView<'_, T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        self.view().slice(rows, cols)
+    }
+
+    pub fn slice_mut<R, C>(&mut self, rows: R, cols: C) -> MatrixViewMut<'_, T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let size: Size = self.size.clone();
+        let (row_start, row_end) = resolve_range(rows, size.rows());
+        let (col_start, col_end) = resolve_range(cols, size.cols());
+
+        MatrixViewMut {
+            data: &mut self.elements,
+            offset: row_start * size.cols() + col_start,
+            rows: row_end - row_start,
+            cols: col_end - col_start,
+            row_stride: size.cols(),
+            col_stride: 1,
+        }
+    }
+
+    // transposed view sharing the storage, see Matrix::transpose for an owned copy
+    pub fn transposed(&self) -> MatrixView<'_, T> {
+        self.view().transpose()
+    }
+}
+
+fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start: usize = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end: usize = match range.end_bound() {
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end && end <= len,
+        "range {start}..{end} is out of bounds for length {len}"
+    );
+    (start, end)
+}
+
+fn out_of_bounds(row: usize, col: usize, size: Size) -> LinearError {
+    LinearError::IndexOutOfBounds { row, col, size }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        matrix::{matrix::Matrix, size::Size},
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::MatrixView;
+
+    #[test]
+    fn test_slice() {
+        let matrix: Matrix<i32> = get_default_matrix();
+        let block: MatrixView<'_, i32> = matrix.slice(1..3, 0..2);
+
+        assert_eq!(block.size(), Size::new(2, 2));
+        assert_eq!(block[(0, 0)], 5);
+        assert_eq!(block[(1, 1)], 10);
+        assert!(block
+            .to_matrix()
+            .equals(&Matrix::new(&vec![vec![5, 6], vec![9, 10]])));
+        assert_eq!(block.get(2, 0), None);
+    }
+
+    #[test]
+    fn test_row_col() {
+        let matrix: Matrix<i32> = get_default_matrix();
+
+        assert_eq!(
+            matrix.row(1).iter().copied().collect::<Vec<i32>>(),
+            vec![5, 6, 7, 8]
+        );
+        assert_eq!(
+            matrix.col(2).iter().copied().collect::<Vec<i32>>(),
+            vec![3, 7, 11]
+        );
+        assert_eq!(matrix.col(2).size(), Size::new(3, 1));
+    }
+
+    #[test]
+    fn test_transpose_and_step() {
+        let matrix: Matrix<i32> = get_default_matrix();
+
+        assert!(matrix.transposed().to_matrix().equals(&matrix.transpose()));
+        assert!(matrix
+            .view()
+            .step_by(2, 3)
+            .to_matrix()
+            .equals(&Matrix::new(&vec![vec![1, 4], vec![9, 12]])));
+        assert_eq!(matrix.slice(1.., 1..).transpose()[(2, 1)], 12);
+    }
+
+    #[test]
+    fn test_product_with_views() {
+        let matrix: Matrix<i32> = get_default_matrix();
+        let expected: Matrix<i32> = matrix.product(&matrix.transpose()).unwrap();
+
+        assert!(matrix
+            .product(&matrix.transposed())
+            .unwrap()
+            .equals(&expected));
+        assert!(matrix
+            .view()
+            .product(&matrix.transposed())
+            .unwrap()
+            .equals(&expected));
+        assert!(matrix
+            .slice(0..2, 1..)
+            .product(&matrix.slice(.., 3..))
+            .unwrap()
+            .equals(&Matrix::new(&vec![vec![80], vec![176]])));
+        assert!(matrix.slice(0..2, ..).product(&matrix.col(3)).is_err());
+
+        let vector: Vector<i32> = Vector::new(vec![1, 1], Shape::Col);
+        let product: Vector<i32> = matrix.slice(.., 1..3).vector_product(&vector).unwrap();
+        assert!(product.equals(&Vector::new(vec![5, 13, 21], Shape::Col)));
+    }
+
+    #[test]
+    fn test_sum_and_norms() {
+        let matrix: Matrix<f64> = Matrix::new(&vec![vec![1.0, -2.0], vec![-3.0, 4.0]]);
+
+        assert_eq!(matrix.slice(.., 1..).sum(), 2.0);
+        assert_eq!(matrix.view().norm_one(), 6.0);
+        assert_eq!(matrix.view().norm_inf(), 7.0);
+        assert!((matrix.view().norm_frobenius() - 30.0_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_view_mut() {
+        let mut matrix: Matrix<i32> = get_default_matrix();
+        let source: Matrix<i32> = Matrix::new(&vec![vec![0, 0], vec![0, 0]]);
+
+        matrix.slice_mut(0..2, 2..).assign(&source).unwrap();
+        matrix.slice_mut(2.., ..).transpose()[(3, 0)] = 100;
+
+        assert!(matrix.equals(&Matrix::new(&vec![
+            vec![1, 2, 0, 0],
+            vec![5, 6, 0, 0],
+            vec![9, 10, 11, 100],
+        ])));
+        assert!(matrix.slice_mut(.., ..1).assign(&source).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_slice_out_of_bounds() {
+        let matrix: Matrix<i32> = get_default_matrix();
+        matrix.slice(2..4, ..);
+    }
+
+    fn get_default_matrix() -> Matrix<i32> {
+        Matrix::new(&vec![
+            vec![1, 2, 3, 4],
+            vec![5, 6, 7, 8],
+            vec![9, 10, 11, 12],
+        ])
+    }
+}
@@ -1,58 +1,273 @@
 use std::{
     fmt::Display,
-    ops::{AddAssign, MulAssign},
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub},
 };
 
 use rand::distributions::uniform::SampleUniform;
 
-pub trait Num: AddAssign + MulAssign + Copy + Sized + Display + PartialOrd + SampleUniform {
+pub trait Num:
+    AddAssign + MulAssign + Clone + Sized + Send + Sync + Display + PartialOrd + SampleUniform
+{
     fn zero() -> Self;
     fn one() -> Self;
-    fn minus_one() -> Self;
     fn from_usize(u: usize) -> Self;
     fn absolute(&self) -> Self;
-}
+    // Smallest and largest representable value, infinities for floats. Unbounded
+    // types like Rational return sentinels instead, which are not real bounds: there
+    // are values below lowest() and above highest(). They are only what max/min
+    // report for an empty matrix or vector.
+    fn lowest() -> Self;
+    fn highest() -> Self;
 
-impl Num for i32 {
-    fn zero() -> Self {
-        0
+    // complex conjugate, the identity for real numbers
+    fn conjugate(&self) -> Self {
+        self.clone()
     }
+}
 
-    fn from_usize(u: usize) -> Self {
-        u as i32
-    }
+// Largest element, T::lowest() if there is none. The search does not start from
+// T::lowest() since unbounded types like Rational have no real lowest value, and
+// unordered elements (NaN) are skipped.
+pub(crate) fn max_of<'a, T: Num + 'a, I: Iterator<Item = &'a T>>(elements: I) -> T {
+    extreme(elements, |e, best| e > best).unwrap_or_else(T::lowest)
+}
 
-    fn minus_one() -> Self {
-        -1
-    }
+// smallest element, T::highest() if there is none
+pub(crate) fn min_of<'a, T: Num + 'a, I: Iterator<Item = &'a T>>(elements: I) -> T {
+    extreme(elements, |e, best| e < best).unwrap_or_else(T::highest)
+}
 
-    fn one() -> Self {
-        1
+// Index of the largest element, the first one on ties. Like max_of it skips NaN, so
+// there is none if every element is unordered or there are no elements at all.
+pub(crate) fn argmax_of<'a, T: Num + 'a, I: Iterator<Item = &'a T>>(elements: I) -> Option<usize> {
+    let mut best: Option<(usize, &T)> = None;
+    for (i, e) in elements
+        .enumerate()
+        .filter(|(_, e)| e.partial_cmp(e).is_some())
+    {
+        if best.is_none_or(|(_, b)| e > b) {
+            best = Some((i, e));
+        }
     }
+    best.map(|(i, _)| i)
+}
 
-    fn absolute(&self) -> Self {
-        self.abs()
+fn extreme<'a, T, I, F>(elements: I, better: F) -> Option<T>
+where
+    T: Num + 'a,
+    I: Iterator<Item = &'a T>,
+    F: Fn(&T, &T) -> bool,
+{
+    let mut best: Option<&T> = None;
+    for e in elements.filter(|e| e.partial_cmp(e).is_some()) {
+        if best.is_none_or(|b| better(e, b)) {
+            best = Some(e);
+        }
     }
+    best.cloned()
 }
 
-impl Num for f64 {
-    fn zero() -> Self {
-        0.0
-    }
+// Floating point element types, for algorithms that need square roots, logarithms
+// or a machine epsilon. Unlike Num in general they are Copy.
+pub trait Float:
+    Num
+    + Copy
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn epsilon() -> Self;
+    fn nan() -> Self;
+    fn is_nan(self) -> bool;
+    fn is_finite(self) -> bool;
+    fn from_f64(f: f64) -> Self;
+    fn to_f64(self) -> f64;
+}
 
-    fn from_usize(u: usize) -> Self {
-        u as f64
-    }
+macro_rules! impl_num_signed {
+    ($($t:ty)*) => {$(
+        impl Num for $t {
+            fn zero() -> Self {
+                0
+            }
 
-    fn minus_one() -> Self {
-        -1.0
-    }
+            fn one() -> Self {
+                1
+            }
+
+            fn from_usize(u: usize) -> Self {
+                u as $t
+            }
+
+            fn absolute(&self) -> Self {
+                self.abs()
+            }
+
+            fn lowest() -> Self {
+                <$t>::MIN
+            }
+
+            fn highest() -> Self {
+                <$t>::MAX
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_num_unsigned {
+    ($($t:ty)*) => {$(
+        impl Num for $t {
+            fn zero() -> Self {
+                0
+            }
+
+            fn one() -> Self {
+                1
+            }
+
+            fn from_usize(u: usize) -> Self {
+                u as $t
+            }
+
+            fn absolute(&self) -> Self {
+                *self
+            }
+
+            fn lowest() -> Self {
+                <$t>::MIN
+            }
+
+            fn highest() -> Self {
+                <$t>::MAX
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_float {
+    ($($t:ident)*) => {$(
+        impl Num for $t {
+            fn zero() -> Self {
+                0.0
+            }
 
-    fn one() -> Self {
-        1.0
+            fn one() -> Self {
+                1.0
+            }
+
+            fn from_usize(u: usize) -> Self {
+                u as $t
+            }
+
+            fn absolute(&self) -> Self {
+                self.abs()
+            }
+
+            fn lowest() -> Self {
+                $t::NEG_INFINITY
+            }
+
+            fn highest() -> Self {
+                $t::INFINITY
+            }
+        }
+
+        impl Float for $t {
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                $t::powi(self, n)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                $t::powf(self, n)
+            }
+
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                $t::hypot(self, other)
+            }
+
+            fn epsilon() -> Self {
+                $t::EPSILON
+            }
+
+            fn nan() -> Self {
+                $t::NAN
+            }
+
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+
+            fn from_f64(f: f64) -> Self {
+                f as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    )*};
+}
+
+impl_num_signed!(i8 i16 i32 i64 i128 isize);
+impl_num_unsigned!(u8 u16 u32 u64 u128 usize);
+impl_float!(f32 f64);
+
+#[cfg(test)]
+mod test {
+    use super::{Float, Num};
+
+    #[test]
+    fn test_num() {
+        assert_eq!(u8::from_usize(7), 7);
+        assert_eq!(5u32.absolute(), 5);
+        assert_eq!((-5i64).absolute(), 5);
+        assert_eq!(i16::lowest(), i16::MIN);
+        assert_eq!(f32::highest(), f32::INFINITY);
     }
 
-    fn absolute(&self) -> Self {
-        self.abs()
+    #[test]
+    fn test_float() {
+        fn hypotenuse<T: Float>(a: T, b: T) -> T {
+            (a * a + b * b).sqrt()
+        }
+
+        assert_eq!(hypotenuse(3.0_f32, 4.0), 5.0);
+        assert!((Float::ln(Float::exp(2.0_f64)) - 2.0).abs() < 1e-15);
+        assert!(<f32 as Float>::nan().is_nan());
+        assert_eq!(<f64 as Float>::epsilon(), f64::EPSILON);
     }
 }
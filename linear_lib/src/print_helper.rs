@@ -1,58 +1,121 @@
-use std::ops::{Add, Mul};
-
-use crate::{matrix::matrix::Matrix, num::Num};
+use std::fmt::{self, Display};
 
 pub struct PrintHelper {}
 
+// Dimensions longer than 2 * EDGE show only their first and last EDGE entries.
+pub const EDGE: usize = 4;
+
 impl PrintHelper {
-    pub fn print_col<T>(elements: &[T], max: T)
-    where
-        T: Num,
-    {
-        if elements.is_empty() {
-            println!("[]");
-            return;
+    // indices to show for a dimension of length n, None where the middle is elided
+    pub fn visible(n: usize) -> Vec<Option<usize>> {
+        if n <= 2 * EDGE {
+            return (0..n).map(Some).collect();
         }
+        (0..EDGE)
+            .map(Some)
+            .chain([None])
+            .chain((n - EDGE..n).map(Some))
+            .collect()
+    }
 
-        let width: usize = max.to_string().len();
-
-        let first = elements.first().unwrap();
-        println!("⎡ {first:>width$} ⎤");
-
-        for element in elements.iter().take(elements.len() - 1).skip(1) {
-            println!("| {element:>width$} |");
+    // element with the given number of digits after the decimal point, ignored by integers
+    pub fn format_element<T: Display>(element: &T, precision: Option<usize>) -> String {
+        match precision {
+            Some(p) => format!("{element:.p$}"),
+            None => element.to_string(),
         }
-
-        let last = elements.last().unwrap();
-        println!("⎣ {last:>width$} ⎦");
     }
 
-    pub fn print_rows<T: Mul<Output = T> + Add<Output = T> + Num>(matrix: &Matrix<T>, max: T) {
-        let width: usize = max.to_string().len();
-        for r in 0..matrix.size().rows() {
-            let row: Vec<&T> = matrix.get_row(r);
-
-            let first = row.first().unwrap();
-            if r == 0 {
-                print!("⎡ {first:>width$} ");
-            } else if r == matrix.size().rows() - 1 {
-                print!("⎣ {first:>width$} ");
+    // Formatted elements of a rows x cols grid. With elide the middle of long
+    // dimensions is replaced by dots = [vertical, horizontal, diagonal].
+    pub fn cells<F>(
+        rows: usize,
+        cols: usize,
+        elide: bool,
+        dots: [&str; 3],
+        element: F,
+    ) -> Vec<Vec<String>>
+    where
+        F: Fn(usize, usize) -> String,
+    {
+        let visible = |n: usize| -> Vec<Option<usize>> {
+            if elide {
+                Self::visible(n)
             } else {
-                print!("⎢ {first:>width$} ");
+                (0..n).map(Some).collect()
             }
+        };
+        let col_indices: Vec<Option<usize>> = visible(cols);
+        visible(rows)
+            .iter()
+            .map(|r| {
+                col_indices
+                    .iter()
+                    .map(|c| match (r, c) {
+                        (Some(r), Some(c)) => element(*r, *c),
+                        (None, Some(_)) => dots[0].to_string(),
+                        (Some(_), None) => dots[1].to_string(),
+                        (None, None) => dots[2].to_string(),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
 
-            for element in row.iter().take(row.len() - 1).skip(1) {
-                print!(" {element:>width$} ");
-            }
+    // Writes a rows x cols grid with brackets spanning all lines:
+    //
+    // ⎡ 1  2 ⎤
+    // ⎢ 3  4 ⎥
+    // ⎣ 5  6 ⎦
+    //
+    // A single line is written as [ 1  2 ]. The middle of long dimensions is replaced
+    // by ⋮ and …, precision and width of the formatter apply to every element.
+    pub fn write_grid<F>(
+        f: &mut fmt::Formatter<'_>,
+        rows: usize,
+        cols: usize,
+        element: F,
+    ) -> fmt::Result
+    where
+        F: Fn(usize, usize, Option<usize>) -> String,
+    {
+        if rows == 0 || cols == 0 {
+            return write!(f, "[]");
+        }
 
-            let last = row.last().unwrap();
-            if r == 0 {
-                println!(" {last:>width$} ⎤");
-            } else if r == matrix.size().rows() - 1 {
-                println!(" {last:>width$} ⎦");
-            } else {
-                println!(" {last:>width$} ⎥");
+        let precision: Option<usize> = f.precision();
+        let cells: Vec<Vec<String>> =
+            Self::cells(rows, cols, true, ["⋮", "…", "⋱"], |r, c| {
+                element(r, c, precision)
+            });
+        let width: usize = cells
+            .iter()
+            .flatten()
+            .map(|cell| cell.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(f.width().unwrap_or(0));
+
+        let last: usize = cells.len() - 1;
+        for (i, row) in cells.iter().enumerate() {
+            let (open, close) = match i {
+                _ if last == 0 => ("[", "]"),
+                0 => ("⎡", "⎤"),
+                _ if i == last => ("⎣", "⎦"),
+                _ => ("⎢", "⎥"),
+            };
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{open} ")?;
+            for (j, cell) in row.iter().enumerate() {
+                if j > 0 {
+                    write!(f, "  ")?;
+                }
+                write!(f, "{cell:>width$}")?;
             }
+            write!(f, " {close}")?;
         }
+        Ok(())
     }
 }
@@ -1,47 +1,133 @@
-use std::fmt::{self};
+use std::{
+    fmt::{self},
+    ops::{Add, Mul},
+};
 
 use crate::{
     matrix::matrix::Matrix,
+    num::Num,
     print_helper::PrintHelper,
     vector::{shape::Shape, vector::Vector},
 };
 
-impl fmt::Debug for Vector<i32> {
+// Row vectors are written on one line, column vectors one element per line.
+impl<T> fmt::Display for Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Shape::Row = self.shape() {
-            writeln!(f, "{:?}", self.elements()).unwrap()
-        } else {
-            PrintHelper::print_col(self.elements(), *self.elements().iter().max().unwrap())
+        let elements: &[T] = self.elements();
+        match self.shape() {
+            Shape::Row => PrintHelper::write_grid(f, 1, elements.len(), |_, c, precision| {
+                PrintHelper::format_element(&elements[c], precision)
+            }),
+            Shape::Col => PrintHelper::write_grid(f, elements.len(), 1, |r, _, precision| {
+                PrintHelper::format_element(&elements[r], precision)
+            }),
         }
-        Ok(())
     }
 }
 
-impl fmt::Debug for Vector<f64> {
+impl<T> fmt::Debug for Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if let Shape::Row = self.shape() {
-            writeln!(f, "{:?}", self.elements()).unwrap()
-        } else {
-            let max = self
-                .elements()
-                .iter()
-                .fold(f64::NEG_INFINITY, |a, &b| a.max(b));
-            PrintHelper::print_col(self.elements(), max)
-        }
-        Ok(())
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<T> fmt::Display for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        PrintHelper::write_grid(
+            f,
+            self.size().rows(),
+            self.size().cols(),
+            |r, c, precision| PrintHelper::format_element(&self[(r, c)], precision),
+        )
     }
 }
 
-impl fmt::Debug for Matrix<f64> {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        PrintHelper::print_rows(self, self.max());
-        Ok(())
+impl<T> fmt::Debug for Matrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
     }
 }
 
-impl fmt::Debug for Matrix<i32> {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        PrintHelper::print_rows(self, self.max());
-        Ok(())
+#[cfg(test)]
+mod test {
+    use crate::{
+        matrix::matrix::Matrix,
+        rational::Rational,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    #[test]
+    fn test_matrix() {
+        let matrix: Matrix<f64> = Matrix::new(&vec![
+            vec![1.0, -2.5, 3.0],
+            vec![4.25, 5.0, 6.0],
+            vec![7.0, 8.0, 90.0],
+        ]);
+
+        assert_eq!(
+            format!("{matrix}"),
+            "⎡    1  -2.5     3 ⎤\n⎢ 4.25     5     6 ⎥\n⎣    7     8    90 ⎦"
+        );
+        assert_eq!(
+            format!("{matrix:.1}"),
+            "⎡  1.0  -2.5   3.0 ⎤\n⎢  4.2   5.0   6.0 ⎥\n⎣  7.0   8.0  90.0 ⎦"
+        );
+        assert_eq!(format!("{matrix:?}"), format!("{matrix}"));
+    }
+
+    #[test]
+    fn test_single_row() {
+        let matrix: Matrix<i32> = Matrix::new(&vec![vec![1, -20, 3]]);
+        assert_eq!(format!("{matrix}"), "[   1  -20    3 ]");
+        assert_eq!(format!("{matrix:4}"), "[    1   -20     3 ]");
+
+        let fractions: Matrix<Rational> =
+            Matrix::new(&vec![vec![Rational::new(1, 3), Rational::from(2)]]);
+        assert_eq!(format!("{fractions:.2}"), "[ 1/3    2 ]");
+    }
+
+    #[test]
+    fn test_vector() {
+        let row: Vector<i32> = Vector::new(vec![1, 2, 3], Shape::Row);
+        let col: Vector<f64> = Vector::new(vec![0.5, -1.0, 2.0], Shape::Col);
+        let single: Vector<f64> = Vector::new(vec![1.23456], Shape::Col);
+
+        assert_eq!(format!("{row:?}"), "[ 1  2  3 ]");
+        assert_eq!(format!("{col:.2}"), "⎡  0.50 ⎤\n⎢ -1.00 ⎥\n⎣  2.00 ⎦");
+        assert_eq!(format!("{single:.3}"), "[ 1.235 ]");
+        assert_eq!(format!("{}", Vector::<i32>::new(vec![], Shape::Row)), "[]");
+    }
+
+    #[test]
+    fn test_elision() {
+        let matrix: Matrix<usize> = Matrix::new(
+            &(0..10)
+                .map(|r| (0..12).map(|c| r * 12 + c).collect())
+                .collect(),
+        );
+        let lines: Vec<String> = format!("{matrix}").lines().map(String::from).collect();
+
+        assert_eq!(lines.len(), 9);
+        assert_eq!(lines[0], "⎡   0    1    2    3    …    8    9   10   11 ⎤");
+        assert_eq!(lines[4], "⎢   ⋮    ⋮    ⋮    ⋮    ⋱    ⋮    ⋮    ⋮    ⋮ ⎥");
+        assert_eq!(lines[8], "⎣ 108  109  110  111    …  116  117  118  119 ⎦");
+
+        let vector: Vector<i32> = Vector::new((1..=100).collect(), Shape::Row);
+        assert_eq!(
+            format!("{vector}"),
+            "[   1    2    3    4    …   97   98   99  100 ]"
+        );
     }
 }
@@ -0,0 +1,372 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use num_bigint::{BigInt, Sign, UniformBigInt};
+use num_integer::Integer;
+use num_traits::{Signed, ToPrimitive, Zero};
+use rand::{
+    distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler},
+    Rng,
+};
+
+use crate::num::Num;
+
+// Exact fraction numerator / denominator, always kept in lowest terms with a
+// positive denominator so equal values have equal representations. Both parts are
+// arbitrary precision, so Rational is Clone but not Copy and never overflows.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Rational {
+    numerator: BigInt,
+    denominator: BigInt,
+}
+
+impl Rational {
+    // Panics for a zero denominator.
+    pub fn new<N: Into<BigInt>, D: Into<BigInt>>(numerator: N, denominator: D) -> Self {
+        let (numerator, denominator): (BigInt, BigInt) = (numerator.into(), denominator.into());
+        assert!(!denominator.is_zero(), "rational with zero denominator");
+
+        let mut divisor: BigInt = numerator.gcd(&denominator);
+        if denominator.is_negative() {
+            divisor = -divisor;
+        }
+        Rational {
+            numerator: numerator / &divisor,
+            denominator: denominator / &divisor,
+        }
+    }
+
+    pub fn from_integer<N: Into<BigInt>>(n: N) -> Self {
+        Rational {
+            numerator: n.into(),
+            denominator: BigInt::from(1),
+        }
+    }
+
+    pub fn numerator(&self) -> &BigInt {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &BigInt {
+        &self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == BigInt::from(1)
+    }
+
+    // Panics for zero.
+    pub fn recip(&self) -> Self {
+        Rational::new(self.denominator.clone(), self.numerator.clone())
+    }
+
+    // Nearest f64, infinite or zero when the value is out of its range. Both parts are
+    // cut to their leading 64 bits first so huge numerators and denominators still divide.
+    pub fn to_f64(&self) -> f64 {
+        let (numerator, n_shift) = leading_bits(&self.numerator);
+        let (denominator, d_shift) = leading_bits(&self.denominator);
+        // scaled in two steps, the quotient can bring a too large power of two back in range
+        let exponent: i32 = (n_shift - d_shift).clamp(-4096, 4096) as i32;
+        numerator / denominator * 2.0_f64.powi(exponent / 2) * 2.0_f64.powi(exponent - exponent / 2)
+    }
+}
+
+impl Num for Rational {
+    fn zero() -> Self {
+        Rational::from_integer(0)
+    }
+
+    fn one() -> Self {
+        Rational::from_integer(1)
+    }
+
+    fn from_usize(u: usize) -> Self {
+        Rational::from_integer(u)
+    }
+
+    fn absolute(&self) -> Self {
+        Rational {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    // Sentinels, not bounds: Rational is unbounded, so values below lowest() and above
+    // highest() exist. max_of/min_of never compare against them and only return them
+    // for an empty matrix or vector.
+    fn lowest() -> Self {
+        Rational::from_integer(-i128::MAX)
+    }
+
+    fn highest() -> Self {
+        Rational::from_integer(i128::MAX)
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(n: i32) -> Self {
+        Rational::from_integer(n)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational::from_integer(n)
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(n: BigInt) -> Self {
+        Rational::from_integer(n)
+    }
+}
+
+impl Ord for Rational {
+    // a/b < c/d <=> a * d < c * b, both denominators are positive
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.numerator * &other.denominator).cmp(&(&other.numerator * &self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// "n/d", or just "n" for integers, padded to the formatter width.
+// Like for integers the precision is ignored, the value is always exact.
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let magnitude: BigInt = self.numerator.abs();
+        let digits: String = if self.is_integer() {
+            magnitude.to_string()
+        } else {
+            format!("{magnitude}/{}", self.denominator)
+        };
+        f.pad_integral(self.numerator.sign() != Sign::Minus, "", &digits)
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        // a/b + c/d = (a * d/g + c * b/g) / (b * d/g) with g = gcd(b, d)
+        let g: BigInt = self.denominator.gcd(&rhs.denominator);
+        let left: BigInt = self.numerator * (&rhs.denominator / &g);
+        let right: BigInt = rhs.numerator * (&self.denominator / &g);
+        Rational::new(left + right, self.denominator * (rhs.denominator / g))
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        // cross cancel first to keep the intermediate products small
+        let g1: BigInt = self.numerator.gcd(&rhs.denominator);
+        let g2: BigInt = rhs.numerator.gcd(&self.denominator);
+        Rational::new(
+            (self.numerator / &g1) * (rhs.numerator / &g2),
+            (self.denominator / g2) * (rhs.denominator / g1),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    // Panics on division by zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        self * rhs.recip()
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Self::Output {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl AddAssign for Rational {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl SubAssign for Rational {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl MulAssign for Rational {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl DivAssign for Rational {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone() / rhs;
+    }
+}
+
+// Samples multiples of 1 / lcm(denominators) in [low, high), so random matrices of
+// integers or halves stay integers or halves.
+pub struct UniformRational {
+    numerator: UniformBigInt,
+    denominator: BigInt,
+}
+
+impl UniformRational {
+    fn bounds(low: &Rational, high: &Rational) -> (BigInt, BigInt, BigInt) {
+        let denominator: BigInt = low.denominator.lcm(&high.denominator);
+        (
+            &low.numerator * (&denominator / &low.denominator),
+            &high.numerator * (&denominator / &high.denominator),
+            denominator,
+        )
+    }
+}
+
+impl UniformSampler for UniformRational {
+    type X = Rational;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high, denominator) = Self::bounds(low.borrow(), high.borrow());
+        UniformRational {
+            numerator: UniformBigInt::new(low, high),
+            denominator,
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high, denominator) = Self::bounds(low.borrow(), high.borrow());
+        UniformRational {
+            numerator: UniformBigInt::new_inclusive(low, high),
+            denominator,
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Rational::new(self.numerator.sample(rng), self.denominator.clone())
+    }
+}
+
+impl SampleUniform for Rational {
+    type Sampler = UniformRational;
+}
+
+// the leading 64 bits of n as f64 and the number of bits cut off
+fn leading_bits(n: &BigInt) -> (f64, i64) {
+    let shift: u64 = n.bits().saturating_sub(64);
+    let leading: f64 = (n >> shift).to_f64().unwrap_or(0.0);
+    (leading, shift as i64)
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigInt;
+
+    use crate::{matrix::matrix::Matrix, num::Num};
+
+    use super::Rational;
+
+    #[test]
+    fn test_normalize() {
+        let half: Rational = Rational::new(-3, -6);
+
+        assert_eq!(half, Rational::new(1, 2));
+        assert_eq!(*Rational::new(4, -8).numerator(), BigInt::from(-1));
+        assert_eq!(*Rational::new(4, -8).denominator(), BigInt::from(2));
+        assert_eq!(Rational::new(0, -5), Rational::zero());
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a: Rational = Rational::new(1, 3);
+        let b: Rational = Rational::new(1, 6);
+
+        assert_eq!(a.clone() + b.clone(), Rational::new(1, 2));
+        assert_eq!(a.clone() - b.clone(), Rational::new(1, 6));
+        assert_eq!(a.clone() * b.clone(), Rational::new(1, 18));
+        assert_eq!(a.clone() / b.clone(), Rational::from_integer(2));
+        assert_eq!(-a.clone(), Rational::new(-1, 3));
+        assert!(b < a && Rational::new(-1, 2) < b);
+        assert!(Rational::new(7, 5) < Rational::new(10, 7));
+        assert!(Rational::lowest() < Rational::new(-1, i128::MAX));
+        assert_eq!(Rational::new(-7, 2).absolute(), Rational::new(7, 2));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Rational::new(6, -4).to_string(), "-3/2");
+        assert_eq!(format!("{:>4}", Rational::from(5)), "   5");
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let max: Rational = Rational::from_integer(i128::MAX);
+        let sum: Rational = max.clone() + Rational::one();
+
+        assert_eq!(sum.to_string(), "170141183460469231731687303715884105728");
+        assert!(sum > max);
+        assert_eq!(sum.clone() - max, Rational::one());
+
+        // max and min do not start from lowest() and highest()
+        let below: Matrix<Rational> = Matrix::new(&vec![vec![-sum.clone(), -sum.clone() - sum]]);
+        assert!(below.max() < Rational::lowest());
+        assert_eq!(below.min() * Rational::new(1, 2), below.max());
+    }
+
+    #[test]
+    fn test_to_f64() {
+        let ten: BigInt = BigInt::from(10);
+        let huge: Rational = Rational::new(ten.pow(400) + 1, ten.pow(399));
+
+        assert!((Rational::new(1, 3).to_f64() - 1.0 / 3.0).abs() < 1e-16);
+        assert!((huge.to_f64() - 10.0).abs() < 1e-14);
+        assert_eq!(Rational::new(ten.pow(400), 3).to_f64(), f64::INFINITY);
+        assert_eq!(Rational::new(-1, ten.pow(400)).to_f64(), 0.0);
+    }
+
+    #[test]
+    fn test_random() {
+        let matrix: Matrix<Rational> =
+            Matrix::random(Rational::new(-1, 2), Rational::new(3, 4), 3, 3);
+
+        assert!(matrix.min() >= Rational::new(-1, 2));
+        assert!(matrix.max() < Rational::new(3, 4));
+        assert!(
+            (matrix.max() * Rational::from(4)).is_integer(),
+            "samples are multiples of 1/4"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    matrix::view::{AsMatrixView, MatrixView},
+    num::Num,
+    print_helper::PrintHelper,
+};
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum LatexEnvironment {
+    // square brackets
+    BMatrix,
+    // parentheses
+    PMatrix,
+}
+
+#[derive(Clone, Copy)]
+pub enum NumberFormat<T> {
+    // as written by Display
+    Display,
+    // fixed number of digits after the decimal point, ignored by integers
+    Precision(usize),
+    Custom(fn(&T) -> String),
+}
+
+// Renders matrices, views and vectors as LaTeX, GitHub flavored Markdown or HTML.
+// Vectors render as a single row or column depending on their shape.
+pub struct Renderer<T> {
+    format: NumberFormat<T>,
+    // show only the corners of large matrices, like Display does
+    elide: bool,
+}
+
+impl<T: Num> Renderer<T> {
+    pub fn new(format: NumberFormat<T>, elide: bool) -> Self {
+        Renderer { format, elide }
+    }
+
+    // \begin{bmatrix} 1 & 2 \\ 3 & 4 \end{bmatrix}, one row per line
+    pub fn latex<M: AsMatrixView<T>>(&self, matrix: &M, environment: LatexEnvironment) -> String {
+        let name: &str = match environment {
+            LatexEnvironment::BMatrix => "bmatrix",
+            LatexEnvironment::PMatrix => "pmatrix",
+        };
+        let rows: Vec<String> = self
+            .cells(
+                matrix.as_view(),
+                [r"\vdots", r"\cdots", r"\ddots"],
+                |text| text.to_string(),
+            )
+            .iter()
+            .map(|row| row.join(" & "))
+            .collect();
+
+        format!(
+            "\\begin{{{name}}}\n{}\n\\end{{{name}}}",
+            rows.join(" \\\\\n")
+        )
+    }
+
+    // Table with the column indices as header and right aligned columns.
+    pub fn markdown<M: AsMatrixView<T>>(&self, matrix: &M) -> String {
+        let view: MatrixView<'_, T> = matrix.as_view();
+        let header: Vec<String> = PrintHelper::cells(
+            1,
+            view.size().cols(),
+            self.elide,
+            ["", "…", ""],
+            |_, c| c.to_string(),
+        )
+        .remove(0);
+
+        let mut lines: Vec<String> = vec![
+            format!("| {} |", header.join(" | ")),
+            format!("|{}|", vec!["---:"; header.len()].join("|")),
+        ];
+        for row in self.cells(view, ["⋮", "…", "⋱"], |text| text.replace('|', "\\|")) {
+            lines.push(format!("| {} |", row.join(" | ")));
+        }
+        lines.join("\n")
+    }
+
+    // <table> with one <tr> per row, elements are HTML escaped
+    pub fn html<M: AsMatrixView<T>>(&self, matrix: &M) -> String {
+        let mut lines: Vec<String> = vec!["<table>".to_string()];
+        let dots: [&str; 3] = ["&vellip;", "&hellip;", "&dtdot;"];
+        for row in self.cells(matrix.as_view(), dots, Self::escape_html) {
+            let cells: String = row.iter().map(|cell| format!("<td>{cell}</td>")).collect();
+            lines.push(format!("  <tr>{cells}</tr>"));
+        }
+        lines.push("</table>".to_string());
+        lines.join("\n")
+    }
+
+    // formatted and escaped elements, the dots are inserted as they are
+    fn cells(
+        &self,
+        view: MatrixView<'_, T>,
+        dots: [&str; 3],
+        escape: fn(&str) -> String,
+    ) -> Vec<Vec<String>> {
+        PrintHelper::cells(
+            view.size().rows(),
+            view.size().cols(),
+            self.elide,
+            dots,
+            |r, c| {
+                let element: &T = view.get(r, c).unwrap();
+                let text: String = match self.format {
+                    NumberFormat::Display => element.to_string(),
+                    NumberFormat::Precision(p) => PrintHelper::format_element(element, Some(p)),
+                    NumberFormat::Custom(format) => format(element),
+                };
+                escape(&text)
+            },
+        )
+    }
+
+    fn escape_html(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        matrix::matrix::Matrix,
+        rational::Rational,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::{LatexEnvironment, NumberFormat, Renderer};
+
+    #[test]
+    fn test_latex() {
+        let renderer: Renderer<f64> = Renderer::new(NumberFormat::Precision(2), false);
+        let matrix: Matrix<f64> = Matrix::new(&vec![vec![1.0, -0.5], vec![0.125, 4.0]]);
+
+        assert_eq!(
+            renderer.latex(&matrix, LatexEnvironment::BMatrix),
+            "\\begin{bmatrix}\n1.00 & -0.50 \\\\\n0.12 & 4.00\n\\end{bmatrix}"
+        );
+        assert_eq!(
+            renderer.latex(&matrix.col(1), LatexEnvironment::PMatrix),
+            "\\begin{pmatrix}\n-0.50 \\\\\n4.00\n\\end{pmatrix}"
+        );
+
+        let fractions: Renderer<Rational> = Renderer::new(
+            NumberFormat::Custom(|x: &Rational| {
+                format!(r"\frac{{{}}}{{{}}}", x.numerator(), x.denominator())
+            }),
+            false,
+        );
+        let vector: Vector<Rational> = Vector::new(vec![Rational::new(1, 2)], Shape::Row);
+        assert_eq!(
+            fractions.latex(&vector, LatexEnvironment::BMatrix),
+            "\\begin{bmatrix}\n\\frac{1}{2}\n\\end{bmatrix}"
+        );
+    }
+
+    #[test]
+    fn test_markdown() {
+        let renderer: Renderer<i32> = Renderer::new(NumberFormat::Display, false);
+        let matrix: Matrix<i32> = Matrix::new(&vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            renderer.markdown(&matrix),
+            "| 0 | 1 | 2 |\n|---:|---:|---:|\n| 1 | 2 | 3 |\n| 4 | 5 | 6 |"
+        );
+
+        let vector: Vector<i32> = Vector::new((0..20).collect(), Shape::Row);
+        let elided: Renderer<i32> = Renderer::new(NumberFormat::Display, true);
+        assert_eq!(
+            elided.markdown(&vector),
+            "| 0 | 1 | 2 | 3 | … | 16 | 17 | 18 | 19 |\n\
+             |---:|---:|---:|---:|---:|---:|---:|---:|---:|\n\
+             | 0 | 1 | 2 | 3 | … | 16 | 17 | 18 | 19 |"
+        );
+    }
+
+    #[test]
+    fn test_html() {
+        let renderer: Renderer<i32> = Renderer::new(
+            NumberFormat::Custom(|x: &i32| {
+                if *x < 0 {
+                    format!("<b>{x}</b>")
+                } else {
+                    x.to_string()
+                }
+            }),
+            true,
+        );
+        let vector: Vector<i32> = Vector::new(vec![-1, 2], Shape::Col);
+        assert_eq!(
+            renderer.html(&vector),
+            "<table>\n  <tr><td>&lt;b&gt;-1&lt;/b&gt;</td></tr>\n  <tr><td>2</td></tr>\n</table>"
+        );
+
+        let matrix: Matrix<i32> = Matrix::new(&(0..10).map(|r| vec![r; 10]).collect());
+        let html: String = renderer.html(&matrix);
+        assert_eq!(html.lines().count(), 11);
+        assert!(html.contains(
+            "<td>&vellip;</td><td>&vellip;</td><td>&vellip;</td><td>&vellip;</td><td>&dtdot;</td>"
+        ));
+    }
+}
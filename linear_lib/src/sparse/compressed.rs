@@ -0,0 +1,172 @@
+use std::ops::{Add, Mul};
+
+use crate::num::Num;
+
+// Compressed storage shared by CSR and CSC. The inner indices and values of outer
+// line o are stored in indices[offsets[o]..offsets[o + 1]], sorted by index.
+// For CSR the outer lines are rows, for CSC they are columns, so the CSR storage of A
+// is exactly the CSC storage of A^T.
+#[derive(Clone)]
+pub(crate) struct Compressed<T> {
+    pub(crate) outer: usize,
+    pub(crate) inner: usize,
+    pub(crate) offsets: Vec<usize>,
+    pub(crate) indices: Vec<usize>,
+    pub(crate) values: Vec<T>,
+}
+
+impl<T> Compressed<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    // (outer, inner, value) entries in any order, duplicates are summed and zeros dropped
+    pub(crate) fn from_entries<I>(outer: usize, inner: usize, entries: I) -> Self
+    where
+        I: Iterator<Item = (usize, usize, T)>,
+    {
+        let entries: Vec<(usize, usize, T)> = entries.collect();
+
+        // counting sort by outer index
+        let mut starts: Vec<usize> = vec![0; outer + 1];
+        for &(o, _, _) in entries.iter() {
+            starts[o + 1] += 1;
+        }
+        for o in 0..outer {
+            starts[o + 1] += starts[o];
+        }
+        let mut next: Vec<usize> = starts.clone();
+        let mut bucketed: Vec<(usize, T)> = vec![(0, T::zero()); entries.len()];
+        for (o, i, val) in entries {
+            bucketed[next[o]] = (i, val);
+            next[o] += 1;
+        }
+
+        let mut offsets: Vec<usize> = Vec::with_capacity(outer + 1);
+        let mut indices: Vec<usize> = Vec::with_capacity(bucketed.len());
+        let mut values: Vec<T> = Vec::with_capacity(bucketed.len());
+        offsets.push(0);
+        for o in 0..outer {
+            let line: &mut [(usize, T)] = &mut bucketed[starts[o]..starts[o + 1]];
+            line.sort_by_key(|&(i, _)| i);
+
+            let mut merged: Vec<(usize, T)> = Vec::with_capacity(line.len());
+            for (i, val) in line.iter() {
+                match merged.last_mut() {
+                    Some((last, sum)) if last == i => *sum += val.clone(),
+                    _ => merged.push((*i, val.clone())),
+                }
+            }
+            for (i, val) in merged {
+                if val != T::zero() {
+                    indices.push(i);
+                    values.push(val);
+                }
+            }
+            offsets.push(indices.len());
+        }
+
+        Compressed {
+            outer,
+            inner,
+            offsets,
+            indices,
+            values,
+        }
+    }
+
+    pub(crate) fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    pub(crate) fn get(&self, o: usize, i: usize) -> T {
+        let line: std::ops::Range<usize> = self.offsets[o]..self.offsets[o + 1];
+        match self.indices[line.clone()].binary_search(&i) {
+            Ok(k) => self.values[line.start + k].clone(),
+            Err(_) => T::zero(),
+        }
+    }
+
+    // (outer, inner, value) in storage order
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        (0..self.outer).flat_map(move |o| {
+            (self.offsets[o]..self.offsets[o + 1])
+                .map(move |k| (o, self.indices[k], &self.values[k]))
+        })
+    }
+
+    // Swaps the roles of outer and inner, turning CSR into CSC of the same matrix.
+    // Walking the outer lines in order keeps every new line sorted.
+    pub(crate) fn transpose(&self) -> Self {
+        let mut offsets: Vec<usize> = vec![0; self.inner + 1];
+        for &i in self.indices.iter() {
+            offsets[i + 1] += 1;
+        }
+        for i in 0..self.inner {
+            offsets[i + 1] += offsets[i];
+        }
+
+        let mut next: Vec<usize> = offsets.clone();
+        let mut indices: Vec<usize> = vec![0; self.nnz()];
+        let mut values: Vec<T> = vec![T::zero(); self.nnz()];
+        for (o, i, val) in self.iter() {
+            indices[next[i]] = o;
+            values[next[i]] = val.clone();
+            next[i] += 1;
+        }
+
+        Compressed {
+            outer: self.inner,
+            inner: self.outer,
+            offsets,
+            indices,
+            values,
+        }
+    }
+
+    // Gustavson's algorithm: line o of the result is the sum of the lines of right
+    // selected by the entries of line o of self, gathered in a dense accumulator.
+    // Requires self.inner == right.outer. Entries that cancel to zero are dropped.
+    pub(crate) fn product(&self, right: &Compressed<T>) -> Self {
+        let mut offsets: Vec<usize> = Vec::with_capacity(self.outer + 1);
+        let mut indices: Vec<usize> = vec![];
+        let mut values: Vec<T> = vec![];
+        let mut accumulator: Vec<T> = vec![T::zero(); right.inner];
+        // outer line that last touched each accumulator slot
+        let mut marker: Vec<usize> = vec![usize::MAX; right.inner];
+        let mut pattern: Vec<usize> = vec![];
+
+        offsets.push(0);
+        for o in 0..self.outer {
+            pattern.clear();
+            for k in self.offsets[o]..self.offsets[o + 1] {
+                let p: usize = self.indices[k];
+                for l in right.offsets[p]..right.offsets[p + 1] {
+                    let i: usize = right.indices[l];
+                    if marker[i] != o {
+                        marker[i] = o;
+                        accumulator[i] = T::zero();
+                        pattern.push(i);
+                    }
+                    accumulator[i] += self.values[k].clone() * right.values[l].clone();
+                }
+            }
+
+            pattern.sort_unstable();
+            for &i in pattern.iter() {
+                if accumulator[i] != T::zero() {
+                    indices.push(i);
+                    values.push(accumulator[i].clone());
+                }
+            }
+            offsets.push(indices.len());
+        }
+
+        Compressed {
+            outer: self.outer,
+            inner: right.inner,
+            offsets,
+            indices,
+            values,
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use std::ops::{Add, Mul};
+
+use crate::{
+    error::LinearError,
+    matrix::{matrix::Matrix, size::Size},
+    num::Num,
+};
+
+use super::{compressed::Compressed, csc::CscMatrix, csr::CsrMatrix};
+
+// Coordinate format: (row, col, value) triplets pushed in any order, the cheap way to
+// assemble a sparse matrix before converting it to CSR or CSC for computations.
+// Duplicate entries are kept and summed on conversion, like finite element assembly
+// expects.
+#[derive(Clone)]
+pub struct CooMatrix<T> {
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    values: Vec<T>,
+    size: Size,
+}
+
+impl<T> CooMatrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    pub fn new(rows: usize, cols: usize) -> Self {
+        CooMatrix {
+            rows: vec![],
+            cols: vec![],
+            values: vec![],
+            size: Size::new(rows, cols),
+        }
+    }
+
+    pub fn from_matrix(matrix: &Matrix<T>) -> Self {
+        let mut coo: CooMatrix<T> = CooMatrix::new(matrix.size().rows(), matrix.size().cols());
+        for (k, val) in matrix.elements.iter().enumerate() {
+            if *val != T::zero() {
+                coo.rows.push(k / matrix.size().cols());
+                coo.cols.push(k % matrix.size().cols());
+                coo.values.push(val.clone());
+            }
+        }
+        coo
+    }
+
+    pub fn push(&mut self, row: usize, col: usize, val: T) -> Result<(), LinearError> {
+        if row >= self.size.rows() || col >= self.size.cols() {
+            return Err(LinearError::IndexOutOfBounds {
+                row,
+                col,
+                size: self.size.clone(),
+            });
+        }
+
+        self.rows.push(row);
+        self.cols.push(col);
+        self.values.push(val);
+        Ok(())
+    }
+
+    pub fn size(&self) -> &Size {
+        &self.size
+    }
+
+    // number of pushed entries, duplicates included
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    // (row, col, value) in the order the entries were pushed
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        self.rows
+            .iter()
+            .zip(self.cols.iter())
+            .zip(self.values.iter())
+            .map(|((&r, &c), val)| (r, c, val))
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let cols: usize = self.size.cols();
+        let mut elements: Vec<T> = vec![T::zero(); self.size.rows() * cols];
+        for (r, c, val) in self.iter() {
+            elements[r * cols + c] += val.clone();
+        }
+
+        Matrix {
+            elements,
+            size: self.size.clone(),
+        }
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        CsrMatrix {
+            storage: Compressed::from_entries(
+                self.size.rows(),
+                self.size.cols(),
+                self.iter().map(|(r, c, val)| (r, c, val.clone())),
+            ),
+            size: self.size.clone(),
+        }
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        CscMatrix {
+            storage: Compressed::from_entries(
+                self.size.cols(),
+                self.size.rows(),
+                self.iter().map(|(r, c, val)| (c, r, val.clone())),
+            ),
+            size: self.size.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        error::LinearError,
+        matrix::{matrix::Matrix, size::Size},
+        sparse::{csc::CscMatrix, csr::CsrMatrix},
+    };
+
+    use super::CooMatrix;
+
+    #[test]
+    fn test_assemble() {
+        // 1D Laplacian assembled from 2x2 element matrices, shared nodes are summed
+        let mut coo: CooMatrix<f64> = CooMatrix::new(4, 4);
+        for e in 0..3 {
+            coo.push(e, e, 1.0).unwrap();
+            coo.push(e, e + 1, -1.0).unwrap();
+            coo.push(e + 1, e, -1.0).unwrap();
+            coo.push(e + 1, e + 1, 1.0).unwrap();
+        }
+        let expected: Matrix<f64> = Matrix::new(&vec![
+            vec![1.0, -1.0, 0.0, 0.0],
+            vec![-1.0, 2.0, -1.0, 0.0],
+            vec![0.0, -1.0, 2.0, -1.0],
+            vec![0.0, 0.0, -1.0, 1.0],
+        ]);
+
+        assert_eq!(coo.nnz(), 12);
+        assert!(coo.to_matrix().equals(&expected));
+
+        let csr: CsrMatrix<f64> = coo.to_csr();
+        assert_eq!(csr.nnz(), 10);
+        assert_eq!(csr.row_offsets(), &[0, 2, 5, 8, 10]);
+        assert!(csr.to_matrix().equals(&expected));
+
+        let csc: CscMatrix<f64> = coo.to_csc();
+        assert_eq!(csc.nnz(), 10);
+        assert!(csc.to_matrix().equals(&expected));
+    }
+
+    #[test]
+    fn test_cancellation() {
+        let mut coo: CooMatrix<i32> = CooMatrix::new(2, 2);
+        coo.push(1, 0, 3).unwrap();
+        coo.push(0, 1, 2).unwrap();
+        coo.push(1, 0, -3).unwrap();
+
+        assert_eq!(coo.to_csr().nnz(), 1);
+        assert_eq!(coo.to_csr().get(1, 0), Some(0));
+        assert_eq!(
+            CooMatrix::from_matrix(&coo.to_matrix())
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![(0, 1, &2)]
+        );
+    }
+
+    #[test]
+    fn test_push_out_of_bounds() {
+        let mut coo: CooMatrix<i32> = CooMatrix::new(2, 3);
+        assert_eq!(
+            coo.push(2, 0, 1).err(),
+            Some(LinearError::IndexOutOfBounds {
+                row: 2,
+                col: 0,
+                size: Size::new(2, 3)
+            })
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use std::ops::{Add, Mul};
+
+use crate::{
+    error::LinearError,
+    matrix::{matrix::Matrix, size::Size},
+    num::Num,
+    vector::{shape::Shape, vector::Vector},
+};
+
+use super::{compressed::Compressed, csr::CsrMatrix};
+
+// Compressed sparse column matrix. The row indices and values of column c are stored
+// in row_indices[col_offsets[c]..col_offsets[c + 1]], sorted by row.
+// Only non-zero elements are stored, build one with CooMatrix or from_matrix.
+#[derive(Clone)]
+pub struct CscMatrix<T> {
+    pub(crate) storage: Compressed<T>,
+    pub(crate) size: Size,
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    pub fn from_matrix(matrix: &Matrix<T>) -> Self {
+        let cols: usize = matrix.size().cols();
+        let entries = matrix
+            .elements
+            .iter()
+            .enumerate()
+            .map(|(k, val)| (k % cols, k / cols, val.clone()));
+
+        CscMatrix {
+            storage: Compressed::from_entries(cols, matrix.size().rows(), entries),
+            size: matrix.size().clone(),
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let cols: usize = self.size.cols();
+        let mut elements: Vec<T> = vec![T::zero(); self.size.rows() * cols];
+        for (r, c, val) in self.iter() {
+            elements[r * cols + c] = val.clone();
+        }
+
+        Matrix {
+            elements,
+            size: self.size.clone(),
+        }
+    }
+
+    pub fn size(&self) -> &Size {
+        &self.size
+    }
+
+    // number of stored non-zero elements
+    pub fn nnz(&self) -> usize {
+        self.storage.nnz()
+    }
+
+    // None outside of the matrix, zero for elements that are not stored
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if row >= self.size.rows() || col >= self.size.cols() {
+            return None;
+        }
+        Some(self.storage.get(col, row))
+    }
+
+    pub fn col_offsets(&self) -> &[usize] {
+        &self.storage.offsets
+    }
+
+    pub fn row_indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.storage.values
+    }
+
+    // (row, col, value) of the non-zero elements in column-major order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        self.storage.iter().map(|(c, r, val)| (r, c, val))
+    }
+
+    pub fn transpose(&self) -> CscMatrix<T> {
+        CscMatrix {
+            storage: self.storage.transpose(),
+            size: Size::new(self.size.cols(), self.size.rows()),
+        }
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        CsrMatrix {
+            storage: self.storage.transpose(),
+            size: self.size.clone(),
+        }
+    }
+
+    // y = sum of the columns scaled by the elements of the vector
+    pub fn vector_product(&self, vector: &Vector<T>) -> Result<Vector<T>, LinearError> {
+        if self.size.cols() != vector.size() {
+            return Err(LinearError::DimensionMismatch {
+                left: self.size.clone(),
+                right: vector.dimensions(),
+            });
+        }
+
+        let mut elements: Vec<T> = vec![T::zero(); self.size.rows()];
+        for (r, c, val) in self.iter() {
+            elements[r] += val.clone() * vector.elements[c].clone();
+        }
+
+        Ok(Vector::new(elements, Shape::Col))
+    }
+
+    pub fn product(&self, right: &CscMatrix<T>) -> Result<CscMatrix<T>, LinearError> {
+        if self.size.cols() != right.size.rows() {
+            return Err(LinearError::DimensionMismatch {
+                left: self.size.clone(),
+                right: right.size.clone(),
+            });
+        }
+
+        // column c of A * B is the sum of the columns of A scaled by column c of B
+        Ok(CscMatrix {
+            storage: right.storage.product(&self.storage),
+            size: Size::new(self.size.rows(), right.size.cols()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        matrix::matrix::Matrix,
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::CscMatrix;
+
+    #[test]
+    fn test_from_matrix() {
+        let matrix: Matrix<f64> = get_default_matrix();
+        let csc: CscMatrix<f64> = CscMatrix::from_matrix(&matrix);
+
+        assert_eq!(csc.col_offsets(), &[0, 2, 3, 4]);
+        assert_eq!(csc.row_indices(), &[0, 2, 1, 2]);
+        assert_eq!(csc.values(), &[1.5, -1.0, 2.0, 3.0]);
+        assert_eq!(csc.get(2, 0), Some(-1.0));
+        assert_eq!(csc.get(0, 2), Some(0.0));
+        assert!(csc.to_matrix().equals(&matrix));
+        assert!(csc.to_csr().to_csc().to_matrix().equals(&matrix));
+    }
+
+    #[test]
+    fn test_products() {
+        let matrix: Matrix<f64> = get_default_matrix();
+        let csc: CscMatrix<f64> = CscMatrix::from_matrix(&matrix);
+        let vector: Vector<f64> = Vector::new(vec![1.0, -2.0, 0.5], Shape::Col);
+
+        assert!(csc
+            .vector_product(&vector)
+            .unwrap()
+            .equals(&matrix.vector_product(&vector).unwrap()));
+        assert!(csc
+            .transpose()
+            .product(&csc)
+            .unwrap()
+            .to_matrix()
+            .equals(&matrix.transpose().product(&matrix).unwrap()));
+        assert!(csc
+            .product(&csc)
+            .unwrap()
+            .to_matrix()
+            .equals(&matrix.product(&matrix).unwrap()));
+    }
+
+    fn get_default_matrix() -> Matrix<f64> {
+        Matrix::new(&vec![
+            vec![1.5, 0.0, 0.0],
+            vec![0.0, 2.0, 0.0],
+            vec![-1.0, 0.0, 3.0],
+        ])
+    }
+}
@@ -0,0 +1,269 @@
+use std::ops::{Add, Mul};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use rand::{seq::index, Rng};
+
+use crate::{
+    error::LinearError,
+    generator::Generator,
+    matrix::{matrix::Matrix, size::Size},
+    num::Num,
+    vector::{shape::Shape, vector::Vector},
+};
+
+use super::{compressed::Compressed, csc::CscMatrix};
+
+// Compressed sparse row matrix. The column indices and values of row r are stored in
+// col_indices[row_offsets[r]..row_offsets[r + 1]], sorted by column.
+// Only non-zero elements are stored, build one with CooMatrix or from_matrix.
+#[derive(Clone)]
+pub struct CsrMatrix<T> {
+    pub(crate) storage: Compressed<T>,
+    pub(crate) size: Size,
+}
+
+impl<T> CsrMatrix<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    pub fn from_matrix(matrix: &Matrix<T>) -> Self {
+        let cols: usize = matrix.size().cols();
+        let entries = matrix
+            .elements
+            .iter()
+            .enumerate()
+            .map(|(k, val)| (k / cols, k % cols, val.clone()));
+
+        CsrMatrix {
+            storage: Compressed::from_entries(matrix.size().rows(), cols, entries),
+            size: matrix.size().clone(),
+        }
+    }
+
+    // round(density * rows * cols) distinct positions chosen uniformly, with values
+    // uniform in [low, high). Values that come out as zero are not stored.
+    pub fn random<R: Rng + ?Sized>(
+        rng: &mut R,
+        rows: usize,
+        cols: usize,
+        density: f64,
+        low: T,
+        high: T,
+    ) -> Result<Self, LinearError> {
+        if !(0.0..=1.0).contains(&density) {
+            return Err(LinearError::InvalidArgument {
+                name: "density",
+                message: "must be in [0, 1]",
+            });
+        }
+        let nnz: usize = (density * (rows * cols) as f64).round() as usize;
+        let positions: Vec<usize> = index::sample(rng, rows * cols, nnz).into_vec();
+        let values: Vec<T> = Generator::uniform(rng, low, high, nnz);
+        let entries = positions
+            .into_iter()
+            .zip(values)
+            .map(|(k, val)| (k / cols, k % cols, val));
+
+        Ok(CsrMatrix {
+            storage: Compressed::from_entries(rows, cols, entries),
+            size: Size::new(rows, cols),
+        })
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let cols: usize = self.size.cols();
+        let mut elements: Vec<T> = vec![T::zero(); self.size.rows() * cols];
+        for (r, c, val) in self.iter() {
+            elements[r * cols + c] = val.clone();
+        }
+
+        Matrix {
+            elements,
+            size: self.size.clone(),
+        }
+    }
+
+    pub fn size(&self) -> &Size {
+        &self.size
+    }
+
+    // number of stored non-zero elements
+    pub fn nnz(&self) -> usize {
+        self.storage.nnz()
+    }
+
+    // None outside of the matrix, zero for elements that are not stored
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        if row >= self.size.rows() || col >= self.size.cols() {
+            return None;
+        }
+        Some(self.storage.get(row, col))
+    }
+
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.storage.offsets
+    }
+
+    pub fn col_indices(&self) -> &[usize] {
+        &self.storage.indices
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.storage.values
+    }
+
+    // (row, col, value) of the non-zero elements in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        self.storage.iter()
+    }
+
+    pub fn transpose(&self) -> CsrMatrix<T> {
+        // CSC of A is CSR of A^T
+        CsrMatrix {
+            storage: self.storage.transpose(),
+            size: Size::new(self.size.cols(), self.size.rows()),
+        }
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        CscMatrix {
+            storage: self.storage.transpose(),
+            size: self.size.clone(),
+        }
+    }
+
+    // Rows are independent of each other, with the `parallel` feature they are
+    // computed on the rayon thread pool.
+    pub fn vector_product(&self, vector: &Vector<T>) -> Result<Vector<T>, LinearError> {
+        if self.size.cols() != vector.size() {
+            return Err(LinearError::DimensionMismatch {
+                left: self.size.clone(),
+                right: vector.dimensions(),
+            });
+        }
+
+        let row_product = |(r, element): (usize, &mut T)| {
+            for k in self.storage.offsets[r]..self.storage.offsets[r + 1] {
+                *element += self.storage.values[k].clone()
+                    * vector.elements[self.storage.indices[k]].clone();
+            }
+        };
+        let mut elements: Vec<T> = vec![T::zero(); self.size.rows()];
+
+        #[cfg(feature = "parallel")]
+        elements.par_iter_mut().enumerate().for_each(row_product);
+
+        #[cfg(not(feature = "parallel"))]
+        elements.iter_mut().enumerate().for_each(row_product);
+
+        Ok(Vector::new(elements, Shape::Col))
+    }
+
+    pub fn product(&self, right: &CsrMatrix<T>) -> Result<CsrMatrix<T>, LinearError> {
+        if self.size.cols() != right.size.rows() {
+            return Err(LinearError::DimensionMismatch {
+                left: self.size.clone(),
+                right: right.size.clone(),
+            });
+        }
+
+        Ok(CsrMatrix {
+            storage: self.storage.product(&right.storage),
+            size: Size::new(self.size.rows(), right.size.cols()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rand::rngs::StdRng;
+
+    use crate::{
+        error::LinearError,
+        generator::Generator,
+        matrix::{matrix::Matrix, size::Size},
+        vector::{shape::Shape, vector::Vector},
+    };
+
+    use super::CsrMatrix;
+
+    #[test]
+    fn test_from_matrix() {
+        let matrix: Matrix<i32> = get_default_matrix();
+        let csr: CsrMatrix<i32> = CsrMatrix::from_matrix(&matrix);
+
+        assert_eq!(csr.nnz(), 5);
+        assert_eq!(csr.row_offsets(), &[0, 2, 3, 5]);
+        assert_eq!(csr.col_indices(), &[0, 3, 1, 0, 2]);
+        assert_eq!(csr.values(), &[1, 2, 3, 4, 5]);
+        assert_eq!(csr.get(2, 2), Some(5));
+        assert_eq!(csr.get(1, 0), Some(0));
+        assert_eq!(csr.get(3, 0), None);
+        assert!(csr.to_matrix().equals(&matrix));
+    }
+
+    #[test]
+    fn test_iter_transpose() {
+        let csr: CsrMatrix<i32> = CsrMatrix::from_matrix(&get_default_matrix());
+        let entries: Vec<(usize, usize, i32)> = csr.iter().map(|(r, c, &v)| (r, c, v)).collect();
+        assert_eq!(
+            entries,
+            vec![(0, 0, 1), (0, 3, 2), (1, 1, 3), (2, 0, 4), (2, 2, 5)]
+        );
+
+        let transposed: CsrMatrix<i32> = csr.transpose();
+        assert_eq!(transposed.size(), &Size::new(4, 3));
+        assert_eq!(transposed.row_offsets(), &[0, 2, 3, 4, 5]);
+        assert!(transposed
+            .to_matrix()
+            .equals(&get_default_matrix().transpose()));
+    }
+
+    #[test]
+    fn test_products() {
+        let matrix: Matrix<i32> = get_default_matrix();
+        let csr: CsrMatrix<i32> = CsrMatrix::from_matrix(&matrix);
+        let vector: Vector<i32> = Vector::new(vec![1, 2, 3, 4], Shape::Col);
+
+        assert!(csr
+            .vector_product(&vector)
+            .unwrap()
+            .equals(&matrix.vector_product(&vector).unwrap()));
+
+        let product: CsrMatrix<i32> = csr.product(&csr.transpose()).unwrap();
+        assert!(product
+            .to_matrix()
+            .equals(&matrix.product(&matrix.transpose()).unwrap()));
+        assert_eq!(product.nnz(), 5);
+
+        assert_eq!(
+            csr.product(&csr).err(),
+            Some(LinearError::DimensionMismatch {
+                left: Size::new(3, 4),
+                right: Size::new(3, 4)
+            })
+        );
+    }
+
+    #[test]
+    fn test_random() {
+        let mut rng: StdRng = Generator::seeded(5);
+        let csr: CsrMatrix<f64> = CsrMatrix::random(&mut rng, 200, 100, 0.05, 1.0, 2.0).unwrap();
+
+        assert_eq!(csr.nnz(), 1000);
+        assert_eq!(*csr.size(), Size::new(200, 100));
+        assert!(csr.values().iter().all(|&v| (1.0..2.0).contains(&v)));
+
+        let same: CsrMatrix<f64> =
+            CsrMatrix::random(&mut Generator::seeded(5), 200, 100, 0.05, 1.0, 2.0).unwrap();
+        assert_eq!(csr.col_indices(), same.col_indices());
+        assert_eq!(csr.values(), same.values());
+        assert!(CsrMatrix::<f64>::random(&mut rng, 2, 2, 1.5, 1.0, 2.0).is_err());
+    }
+
+    fn get_default_matrix() -> Matrix<i32> {
+        Matrix::new(&vec![vec![1, 0, 0, 2], vec![0, 3, 0, 0], vec![4, 0, 5, 0]])
+    }
+}
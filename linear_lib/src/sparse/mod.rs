@@ -0,0 +1,4 @@
+mod compressed;
+pub mod coo;
+pub mod csc;
+pub mod csr;
@@ -1,2 +1,4 @@
+pub mod ops;
 pub mod shape;
+#[allow(clippy::module_inception)]
 pub mod vector;
@@ -0,0 +1,251 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{error::LinearError, num::Num};
+
+use super::vector::Vector;
+
+// Operators panic on incompatible sizes, use Vector::dot and friends to handle that case.
+
+// implements the owned variants of a binary operator by borrowing both sides
+macro_rules! forward_ref_binop {
+    ($imp:ident, $method:ident) => {
+        impl<T> $imp<Vector<T>> for Vector<T>
+        where
+            T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+        {
+            type Output = Vector<T>;
+
+            fn $method(self, rhs: Vector<T>) -> Self::Output {
+                $imp::$method(&self, &rhs)
+            }
+        }
+
+        impl<T> $imp<&Vector<T>> for Vector<T>
+        where
+            T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+        {
+            type Output = Vector<T>;
+
+            fn $method(self, rhs: &Vector<T>) -> Self::Output {
+                $imp::$method(&self, rhs)
+            }
+        }
+
+        impl<T> $imp<Vector<T>> for &Vector<T>
+        where
+            T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+        {
+            type Output = Vector<T>;
+
+            fn $method(self, rhs: Vector<T>) -> Self::Output {
+                $imp::$method(self, &rhs)
+            }
+        }
+    };
+}
+
+impl<T> Mul<T> for Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|e| e * rhs.clone())
+    }
+}
+
+impl<T> Mul<T> for &Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|e| e * rhs.clone())
+    }
+}
+
+impl<T> Add<T> for Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    type Output = Vector<T>;
+
+    fn add(self, rhs: T) -> Self::Output {
+        self.map(|e| e + rhs.clone())
+    }
+}
+
+impl<T> Add<&Vector<T>> for &Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    type Output = Vector<T>;
+
+    fn add(self, rhs: &Vector<T>) -> Self::Output {
+        let mut sum: Vector<T> = self.clone();
+        sum += rhs;
+        sum
+    }
+}
+
+impl<T> Sub<&Vector<T>> for &Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: &Vector<T>) -> Self::Output {
+        let mut difference: Vector<T> = self.clone();
+        difference -= rhs;
+        difference
+    }
+}
+
+forward_ref_binop!(Add, add);
+forward_ref_binop!(Sub, sub);
+
+impl<T> Neg for &Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Neg<Output = T> + Num,
+{
+    type Output = Vector<T>;
+
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+
+impl<T> Neg for Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Neg<Output = T> + Num,
+{
+    type Output = Vector<T>;
+
+    fn neg(mut self) -> Self::Output {
+        self.elements.iter_mut().for_each(|e| *e = -e.clone());
+        self
+    }
+}
+
+impl<T> AddAssign<&Vector<T>> for Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    fn add_assign(&mut self, rhs: &Vector<T>) {
+        Self::assert_same_size(self, rhs);
+        for (e, r) in self.elements.iter_mut().zip(rhs.elements.iter()) {
+            *e += r.clone();
+        }
+    }
+}
+
+impl<T> AddAssign<Vector<T>> for Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    fn add_assign(&mut self, rhs: Vector<T>) {
+        *self += &rhs;
+    }
+}
+
+impl<T> SubAssign<&Vector<T>> for Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    fn sub_assign(&mut self, rhs: &Vector<T>) {
+        Self::assert_same_size(self, rhs);
+        for (e, r) in self.elements.iter_mut().zip(rhs.elements.iter()) {
+            *e = e.clone() - r.clone();
+        }
+    }
+}
+
+impl<T> SubAssign<Vector<T>> for Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Sub<Output = T> + Num,
+{
+    fn sub_assign(&mut self, rhs: Vector<T>) {
+        *self -= &rhs;
+    }
+}
+
+impl<T> MulAssign<T> for Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        self.elements.iter_mut().for_each(|e| *e *= rhs.clone());
+    }
+}
+
+impl<T> Index<usize> for Vector<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.elements[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Vector<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.elements[index]
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Mul<Output = T> + Add<Output = T> + Num,
+{
+    fn assert_same_size(&self, other: &Vector<T>) {
+        if self.size() != other.size() {
+            panic!(
+                "{}",
+                LinearError::DimensionMismatch {
+                    left: self.dimensions(),
+                    right: other.dimensions(),
+                }
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vector::{shape::Shape, vector::Vector};
+
+    #[test]
+    fn test_add_sub() {
+        let left: Vector<i32> = Vector::new(vec![1, 2, 3], Shape::Col);
+        let right: Vector<i32> = Vector::new(vec![4, 5, 6], Shape::Col);
+
+        assert!((&left + &right).equals(&Vector::new(vec![5, 7, 9], Shape::Col)));
+        assert!((&right - &left).equals(&Vector::new(vec![3, 3, 3], Shape::Col)));
+        assert!((-&left).equals(&Vector::new(vec![-1, -2, -3], Shape::Col)));
+
+        let mut sum: Vector<i32> = left.clone() + right.clone();
+        sum -= right;
+        assert!(sum.equals(&left));
+    }
+
+    #[test]
+    fn test_scalar() {
+        let mut vector: Vector<f64> = Vector::new(vec![1.0, 2.0, 3.0], Shape::Row);
+        vector *= 2.0;
+
+        assert!((&vector * 0.5).equals(&Vector::new(vec![1.0, 2.0, 3.0], Shape::Row)));
+        assert_eq!(vector[2], 6.0);
+
+        vector[0] = 10.0;
+        assert_eq!(*vector.get(0).unwrap(), 10.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn test_add_dimension_mismatch() {
+        let left: Vector<i32> = Vector::new(vec![1, 2, 3], Shape::Col);
+        let right: Vector<i32> = Vector::new(vec![1, 2], Shape::Col);
+        let _ = left + right;
+    }
+}
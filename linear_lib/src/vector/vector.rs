@@ -1,16 +1,21 @@
-use std::ops::{Add, Mul};
+use std::ops::{Add, Div, Mul};
+
+use rand::Rng;
 
 use crate::{
+    error::LinearError,
     generator::Generator,
     matrix::{matrix::Matrix, size::Size},
-    num::Num,
+    num::{argmax_of, max_of, min_of, Float, Num},
 };
 
 use super::shape::Shape;
 
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector<T> {
-    elements: Vec<T>,
-    shape: Shape,
+    pub(crate) elements: Vec<T>,
+    pub(crate) shape: Shape,
 }
 
 impl<T> Vector<T>
@@ -34,12 +39,36 @@ where
     }
 
     pub fn random(low: T, high: T, size: usize, shape: Shape) -> Vector<T> {
+        Self::random_with(&mut rand::thread_rng(), low, high, size, shape)
+    }
+
+    // uniform in [low, high), pass a seeded rng for reproducible vectors
+    pub fn random_with<R: Rng + ?Sized>(
+        rng: &mut R,
+        low: T,
+        high: T,
+        size: usize,
+        shape: Shape,
+    ) -> Vector<T> {
         Vector {
-            elements: Generator::random_elements(low, high, size),
+            elements: Generator::uniform(rng, low, high, size),
             shape,
         }
     }
 
+    // ones with probability p, zeros otherwise
+    pub fn random_bernoulli<R: Rng + ?Sized>(
+        rng: &mut R,
+        p: f64,
+        size: usize,
+        shape: Shape,
+    ) -> Result<Vector<T>, LinearError> {
+        Ok(Vector {
+            elements: Generator::bernoulli(rng, p, size)?,
+            shape,
+        })
+    }
+
     pub fn get(&self, index: usize) -> Option<&T> {
         self.elements.get(index)
     }
@@ -58,19 +87,30 @@ where
         true
     }
 
-    pub fn dot(&self, right: &Vector<T>) -> Result<T, &'static str> {
+    // Hermitian inner product sum(conj(self_i) * right_i), the usual dot product for reals
+    pub fn dot(&self, right: &Vector<T>) -> Result<T, LinearError> {
         if self.size() != right.size() {
-            return Err("invalid vectors size");
+            return Err(LinearError::DimensionMismatch {
+                left: self.dimensions(),
+                right: right.dimensions(),
+            });
         }
 
         let mut product: T = T::zero();
         for i in 0..self.elements.len() {
-            product += self.elements[i] * right.elements[i];
+            product += self.elements[i].conjugate() * right.elements[i].clone();
         }
 
         Ok(product)
     }
 
+    pub fn conjugate(&self) -> Vector<T> {
+        Vector::new(
+            self.elements.iter().map(|e| e.conjugate()).collect(),
+            self.shape,
+        )
+    }
+
     pub fn outer(&self, right: &Vector<T>) -> Matrix<T> {
         let mut matrix = Matrix {
             elements: vec![T::zero(); self.size() * right.size()],
@@ -80,8 +120,8 @@ where
         for row in 0..self.size() {
             for col in 0..matrix.size().cols() {
                 let mut cell: T = T::zero();
-                cell += *self.get(row).unwrap() * *right.get(col).unwrap();
-                matrix.set(row, col, cell);
+                cell += self.elements[row].clone() * right.elements[col].clone();
+                matrix.elements[row * right.size() + col] = cell;
             }
         }
 
@@ -89,15 +129,92 @@ where
     }
 
     pub fn mul(&mut self, val: T) {
-        self.set_elements(self.map(val, |a, &b| a * b));
+        self.map_inplace(|e| e * val.clone());
     }
 
     pub fn add(&mut self, val: T) {
-        self.set_elements(self.map(val, |a, &b| a + b));
+        self.map_inplace(|e| e + val.clone());
     }
 
-    pub fn map(&self, val: T, f: fn(T, &T) -> T) -> Vec<T> {
-        self.elements.iter().map(|e| f(val, e)).collect()
+    // new vector of the same shape with f applied to every element
+    pub fn map<U, F: FnMut(T) -> U>(&self, f: F) -> Vector<U> {
+        Vector {
+            elements: self.elements.iter().cloned().map(f).collect(),
+            shape: self.shape,
+        }
+    }
+
+    pub fn map_inplace<F: FnMut(T) -> T>(&mut self, mut f: F) {
+        for e in self.elements.iter_mut() {
+            *e = f(e.clone());
+        }
+    }
+
+    // f applied to the elements at the same index, the result has the shape of self
+    pub fn zip_with<U, F>(&self, right: &Vector<T>, mut f: F) -> Result<Vector<U>, LinearError>
+    where
+        F: FnMut(T, T) -> U,
+    {
+        if self.size() != right.size() {
+            return Err(LinearError::DimensionMismatch {
+                left: self.dimensions(),
+                right: right.dimensions(),
+            });
+        }
+
+        Ok(Vector {
+            elements: self
+                .elements
+                .iter()
+                .zip(right.elements.iter())
+                .map(|(a, b)| f(a.clone(), b.clone()))
+                .collect(),
+            shape: self.shape,
+        })
+    }
+
+    pub fn fold<A, F: FnMut(A, &T) -> A>(&self, init: A, f: F) -> A {
+        self.elements.iter().fold(init, f)
+    }
+
+    // element-wise product, unlike dot the shapes do not matter
+    pub fn hadamard_product(&self, right: &Vector<T>) -> Result<Vector<T>, LinearError> {
+        self.zip_with(right, |a, b| a * b)
+    }
+
+    pub fn hadamard_division(&self, right: &Vector<T>) -> Result<Vector<T>, LinearError>
+    where
+        T: Div<Output = T>,
+    {
+        self.zip_with(right, |a, b| a / b)
+    }
+
+    // index of the largest element, the first one on ties, NaN is skipped,
+    // None for an empty vector
+    pub fn argmax(&self) -> Option<usize> {
+        argmax_of(self.elements.iter())
+    }
+
+    pub fn sum(&self) -> T {
+        self.fold(T::zero(), |mut a, b| {
+            a += b.clone();
+            a
+        })
+    }
+
+    pub fn mean(&self) -> T
+    where
+        T: Div<Output = T>,
+    {
+        self.sum() / T::from_usize(self.size())
+    }
+
+    pub fn max(&self) -> T {
+        max_of(self.elements.iter())
+    }
+
+    pub fn min(&self) -> T {
+        min_of(self.elements.iter())
     }
 
     pub fn elements(&self) -> &[T] {
@@ -108,42 +225,46 @@ where
         &self.shape
     }
 
-    fn set_elements(&mut self, elements: Vec<T>) {
-        self.elements = elements;
-    }
-}
-
-impl<T> Mul<T> for Vector<T>
-where
-    T: Mul<Output = T> + Add<Output = T> + Num,
-{
-    type Output = Vector<T>;
-
-    fn mul(self, rhs: T) -> Self::Output {
-        let elements = self.map(rhs, |a, &b| a * b);
-        Vector::new(elements, *self.shape())
+    // size of the vector seen as a 1 x n or n x 1 matrix
+    pub fn dimensions(&self) -> Size {
+        match self.shape {
+            Shape::Row => Size::new(1, self.size()),
+            Shape::Col => Size::new(self.size(), 1),
+        }
     }
 }
 
-impl<T> Add<T> for Vector<T>
-where
-    T: Mul<Output = T> + Add<Output = T> + Num,
-{
-    type Output = Vector<T>;
-
-    fn add(self, rhs: T) -> Self::Output {
-        let elements = self.map(rhs, |a, &b| a + b);
-        Vector::new(elements, *self.shape())
+impl<T: Float> Vector<T> {
+    pub fn random_normal<R: Rng + ?Sized>(
+        rng: &mut R,
+        mean: T,
+        std_dev: T,
+        size: usize,
+        shape: Shape,
+    ) -> Result<Vector<T>, LinearError> {
+        Ok(Vector {
+            elements: Generator::normal(rng, mean, std_dev, size)?,
+            shape,
+        })
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{matrix::matrix::Matrix, vector::shape::Shape};
+    use rand::rngs::StdRng;
+
+    use crate::{
+        complex::Complex,
+        error::LinearError,
+        generator::Generator,
+        matrix::{matrix::Matrix, size::Size},
+        num::Num,
+        vector::shape::Shape,
+    };
 
     use super::Vector;
 
-    static ELEMENTS: &'static [i32] = &[1, 3, 5, 2, 7];
+    static ELEMENTS: &[i32] = &[1, 3, 5, 2, 7];
 
     #[test]
     fn test_init() {
@@ -167,7 +288,7 @@ mod test {
         let left: Vector<i32> = Vector::new(ELEMENTS.to_vec(), Shape::Row);
         let right: Vector<i32> = Vector::new(ELEMENTS.to_vec(), Shape::Col);
 
-        let dot: Result<i32, &str> = left.dot(&right);
+        let dot: Result<i32, LinearError> = left.dot(&right);
         assert_eq!(dot.unwrap(), 88);
 
         //right.transpose();
@@ -175,6 +296,49 @@ mod test {
         //assert_eq!(dot.unwrap_err(), "invalid vectors shape");
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let vector: Vector<i32> = Vector::new(ELEMENTS.to_vec(), Shape::Row);
+        let json: String = serde_json::to_string(&vector).unwrap();
+        assert_eq!(json, r#"{"elements":[1,3,5,2,7],"shape":"Row"}"#);
+
+        let read: Vector<i32> = serde_json::from_str(&json).unwrap();
+        assert!(read.equals(&vector));
+        assert_eq!(read.shape(), &Shape::Row);
+    }
+
+    #[test]
+    fn test_dot_hermitian() {
+        let u: Vector<Complex<f64>> = Vector::new(
+            vec![Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)],
+            Shape::Col,
+        );
+        let v: Vector<Complex<f64>> = Vector::new(
+            vec![Complex::new(3.0, 0.0), Complex::new(1.0, -1.0)],
+            Shape::Col,
+        );
+
+        // conj(1 + i) * 3 + conj(2i) * (1 - i) = (3 - 3i) + (-2 - 2i)
+        assert_eq!(u.dot(&v).unwrap(), Complex::new(1.0, -5.0));
+        assert_eq!(u.dot(&u).unwrap(), Complex::new(6.0, 0.0));
+        assert_eq!(v.dot(&u).unwrap(), u.dot(&v).unwrap().conjugate());
+    }
+
+    #[test]
+    fn test_dot_dimension_mismatch() {
+        let left: Vector<i32> = Vector::new(ELEMENTS.to_vec(), Shape::Row);
+        let right: Vector<i32> = Vector::new(vec![3, 2, 1], Shape::Col);
+
+        assert_eq!(
+            left.dot(&right),
+            Err(LinearError::DimensionMismatch {
+                left: Size::new(1, 5),
+                right: Size::new(3, 1)
+            })
+        );
+    }
+
     #[test]
     fn test_random() {
         let size: usize = 100;
@@ -199,17 +363,86 @@ mod test {
         assert_eq!(*v.shape(), Shape::Row);
     }
 
+    #[test]
+    fn test_random_seeded() {
+        let first: Vector<i32> =
+            Vector::random_with(&mut Generator::seeded(4), 0, 10, 20, Shape::Row);
+        let second: Vector<i32> =
+            Vector::random_with(&mut Generator::seeded(4), 0, 10, 20, Shape::Row);
+        assert!(first.equals(&second));
+
+        let mut rng: StdRng = Generator::seeded(5);
+        let noise: Vector<f64> = Vector::random_normal(&mut rng, 0.0, 0.0, 3, Shape::Col).unwrap();
+        assert_eq!(noise.elements(), &[0.0, 0.0, 0.0]);
+
+        let mask: Vector<u8> = Vector::random_bernoulli(&mut rng, 1.0, 4, Shape::Col).unwrap();
+        assert_eq!(mask.elements(), &[1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn test_map() {
+        let mut vec: Vector<i32> = Vector::new(ELEMENTS.to_vec(), Shape::Row);
+        let squares: Vector<i64> = vec.map(|x| (x as i64) * (x as i64));
+
+        assert_eq!(squares.elements(), &[1, 9, 25, 4, 49]);
+        assert_eq!(*squares.shape(), Shape::Row);
+
+        vec.map_inplace(|x| x - 1);
+        assert_eq!(vec.elements(), &[0, 2, 4, 1, 6]);
+        assert_eq!(vec.fold(1, |a, &b| a.max(b)), 6);
+    }
+
+    #[test]
+    fn test_elementwise() {
+        let left: Vector<f64> = Vector::new(vec![1.0, 4.0, 9.0], Shape::Row);
+        let right: Vector<f64> = Vector::new(vec![2.0, 2.0, 3.0], Shape::Col);
+
+        assert_eq!(
+            left.hadamard_product(&right).unwrap().elements(),
+            &[2.0, 8.0, 27.0]
+        );
+        assert_eq!(
+            left.hadamard_division(&right).unwrap().elements(),
+            &[0.5, 2.0, 3.0]
+        );
+        assert_eq!(
+            left.zip_with(&right, |a, b| a > b).unwrap().elements,
+            &[false, true, true]
+        );
+        assert_eq!(
+            left.hadamard_product(&Vector::new(vec![1.0], Shape::Col))
+                .err(),
+            Some(LinearError::DimensionMismatch {
+                left: Size::new(1, 3),
+                right: Size::new(1, 1)
+            })
+        );
+
+        assert_eq!(Vector::new(ELEMENTS.to_vec(), Shape::Row).argmax(), Some(4));
+        assert_eq!(Vector::new(vec![2, 5, 5], Shape::Col).argmax(), Some(1));
+        assert_eq!(Vector::<i32>::new(vec![], Shape::Col).argmax(), None);
+        assert_eq!(
+            Vector::new(vec![5.0, f64::NAN, 1.0], Shape::Col).argmax(),
+            Some(0)
+        );
+        assert_eq!(
+            Vector::new(vec![f64::NAN, 1.0, 2.0], Shape::Col).argmax(),
+            Some(2)
+        );
+        assert_eq!(Vector::new(vec![f64::NAN], Shape::Col).argmax(), None);
+    }
+
     #[test]
     fn test_mul() {
         let mut vec: Vector<i32> = Vector::new(ELEMENTS.to_vec(), Shape::Row);
         vec.mul(2);
-        for i in 0..vec.size() {
-            assert_eq!(*vec.get(i).unwrap(), ELEMENTS[i] * 2);
+        for (i, e) in ELEMENTS.iter().enumerate() {
+            assert_eq!(*vec.get(i).unwrap(), e * 2);
         }
 
         let mul_vec: Vector<i32> = vec * 3;
-        for i in 0..mul_vec.size() {
-            assert_eq!(*mul_vec.get(i).unwrap(), ELEMENTS[i] * 6);
+        for (i, e) in ELEMENTS.iter().enumerate() {
+            assert_eq!(*mul_vec.get(i).unwrap(), e * 6);
         }
     }
 
@@ -217,13 +450,13 @@ mod test {
     fn test_add() {
         let mut vec: Vector<i32> = Vector::new(ELEMENTS.to_vec(), Shape::Row);
         vec.add(2);
-        for i in 0..vec.size() {
-            assert_eq!(*vec.get(i).unwrap(), ELEMENTS[i] + 2);
+        for (i, e) in ELEMENTS.iter().enumerate() {
+            assert_eq!(*vec.get(i).unwrap(), e + 2);
         }
 
         let mul_vec: Vector<i32> = vec + 3;
-        for i in 0..mul_vec.size() {
-            assert_eq!(*mul_vec.get(i).unwrap(), ELEMENTS[i] + 5);
+        for (i, e) in ELEMENTS.iter().enumerate() {
+            assert_eq!(*mul_vec.get(i).unwrap(), e + 5);
         }
     }
 
@@ -241,6 +474,17 @@ mod test {
         assert!(outer_p.equals(&expected));
     }
 
+    #[test]
+    fn test_reductions() {
+        let vector: Vector<i64> = Vector::new(vec![4, -2, 7, 3], Shape::Col);
+        let unsigned: Vector<u16> = Vector::new(vec![4, 2, 9], Shape::Row);
+
+        assert_eq!(vector.sum(), 12);
+        assert_eq!(vector.mean(), 3);
+        assert_eq!((vector.max(), vector.min()), (7, -2));
+        assert_eq!((unsigned.max(), unsigned.min()), (9, 2));
+    }
+
     #[test]
     fn test_equals() {
         let vec1: Vector<i32> = Vector::new(ELEMENTS.to_vec(), Shape::Row);